@@ -0,0 +1,287 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::meta::ParseNestedMeta;
+use syn::{
+    Data, DeriveInput, Error, Expr, ExprLit, Fields, Lit, LitBool, LitInt, Result, Type,
+    parse_macro_input,
+};
+
+// 机器人间通信可用的子内容 ID 范围（0x0200~0x02FF）
+const ID_RANGE: core::ops::RangeInclusive<u16> = 0x0200..=0x02FF;
+
+// 为 deku 结构体实现 RobotMessage 与 WireSize：
+//
+// #[derive(RobotMessage)]
+// #[deku_derive(DekuRead, DekuWrite)]
+// #[derive(Debug, Clone)]
+// #[robot_message(id = 0x0201)]
+// struct Target { #[deku(bits = 4)] id: u8, x: f32 }
+//
+// 编码长度由字段类型的 WireSize 与 deku 的 bits / bytes / pad / skip 属性在编译期求和，
+// 超过 0x0301 负载上限时编译失败；无法静态确定长度的字段可用 #[robot_message(size = N)] 指定字节数。
+// deku_derive 展开时会移除字段上的 deku 属性与 temp 字段，因此本派生须写在 #[deku_derive] 之前，
+// 其余派生写在其后（不含 temp 字段时也可与 DekuRead / DekuWrite 写在同一个 #[derive] 中）。
+// 开启 serde 特性时同时实现 Serialize / Deserialize（要求各字段类型支持 serde，
+// 例如长度超过 32 的数组不支持，可用 #[robot_message(id = 0x0201, serde = false)] 关闭）。
+#[proc_macro_derive(RobotMessage, attributes(robot_message, deku))]
+pub fn derive_robot_message(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2> {
+    let ident = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "RobotMessage cannot be derived for generic types",
+        ));
+    }
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            ident,
+            "RobotMessage can only be derived for structs",
+        ));
+    };
+    let Options { id, serde } = options(input)?;
+
+    let mut bits = Vec::new();
+    for field in &data.fields {
+        bits.push(field_bits(&field.attrs, &field.ty)?);
+    }
+
+    let krate = quote!(::rm_referee_protocol);
+    let private = quote!(#krate::__private);
+    let message = format!("robot message `{ident}` exceeds the 112-byte 0x0301 payload limit");
+    let serde = if serde {
+        serde_impls(input, &data.fields)?
+    } else {
+        TokenStream2::new()
+    };
+
+    Ok(quote! {
+        impl #krate::WireSize for #ident {
+            const BITS: usize = 0 #(+ #bits)*;
+        }
+
+        const _: () = ::core::assert!(
+            <#ident as #krate::WireSize>::BITS.div_ceil(8) <= #krate::MAX_INTERACTION_CONTENT_LEN,
+            #message
+        );
+
+        impl #krate::RobotMessage for #ident {
+            const DATA_CMD_ID: u16 = #id;
+
+            fn encode(&self) -> ::core::result::Result<#private::Vec<u8>, #private::deku::DekuError> {
+                #private::deku::DekuContainerWrite::to_bytes(self)
+            }
+
+            fn decode(bytes: &[u8]) -> ::core::result::Result<Self, #private::deku::DekuError> {
+                <Self as #private::deku::DekuContainerRead>::from_bytes((bytes, 0))
+                    .map(|(_, message)| message)
+            }
+        }
+
+        #serde
+    })
+}
+
+struct Options {
+    id: u16,
+    serde: bool,
+}
+
+// 解析 #[robot_message(id = 0x0201, serde = false)]
+fn options(input: &DeriveInput) -> Result<Options> {
+    let mut id = None;
+    let mut serde = true;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("robot_message"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("id") {
+                let lit: LitInt = meta.value()?.parse()?;
+                let value: u16 = lit.base10_parse()?;
+                if !ID_RANGE.contains(&value) {
+                    return Err(Error::new_spanned(
+                        lit,
+                        "robot message id must be within 0x0200..=0x02FF",
+                    ));
+                }
+                id = Some(value);
+                Ok(())
+            } else if meta.path.is_ident("serde") {
+                serde = meta.value()?.parse::<LitBool>()?.value;
+                Ok(())
+            } else {
+                Err(meta.error("expected `id = 0x02XX` or `serde = false`"))
+            }
+        })?;
+    }
+    let id = id.ok_or_else(|| {
+        Error::new_spanned(
+            &input.ident,
+            "missing `#[robot_message(id = 0x02XX)]` attribute",
+        )
+    })?;
+    Ok(Options { id, serde })
+}
+
+// 跳过 `key = value` 或 `key(...)` 的取值部分
+fn skip_value(meta: &ParseNestedMeta) -> Result<()> {
+    if meta.input.peek(syn::Token![=]) {
+        meta.value()?.parse::<Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        let _content;
+        syn::parenthesized!(_content in meta.input);
+    }
+    Ok(())
+}
+
+// deku 的 temp 字段只参与编解码，展开后不存在于结构体中
+#[cfg(feature = "serde")]
+fn is_temp(field: &syn::Field) -> bool {
+    field
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("deku"))
+        .any(|a| {
+            let mut temp = false;
+            let _ = a.parse_nested_meta(|meta| {
+                temp |= meta.path.is_ident("temp");
+                skip_value(&meta)
+            });
+            temp
+        })
+}
+
+// 单个字段的编码位数表达式
+fn field_bits(attrs: &[syn::Attribute], ty: &Type) -> Result<TokenStream2> {
+    let mut size = None;
+    let mut pad = Vec::new();
+    let mut skip = false;
+    for attr in attrs {
+        if attr.path().is_ident("robot_message") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("size") {
+                    let bytes = lit_usize(&meta.value()?.parse()?)?;
+                    size = Some(quote!(#bytes * 8));
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `size = N`"))
+                }
+            })?;
+        } else if attr.path().is_ident("deku") {
+            attr.parse_nested_meta(|meta| {
+                let key = meta.path.get_ident().map(ToString::to_string);
+                match key.as_deref() {
+                    Some("skip") => skip = true,
+                    Some("bits") if size.is_none() => {
+                        let bits = lit_usize(&meta.value()?.parse()?)?;
+                        size = Some(quote!(#bits));
+                    }
+                    Some("bytes") if size.is_none() => {
+                        let bytes = lit_usize(&meta.value()?.parse()?)?;
+                        size = Some(quote!(#bytes * 8));
+                    }
+                    Some("pad_bits_before" | "pad_bits_after") => {
+                        let bits = lit_usize(&meta.value()?.parse()?)?;
+                        pad.push(quote!(#bits));
+                    }
+                    Some("pad_bytes_before" | "pad_bytes_after") => {
+                        let bytes = lit_usize(&meta.value()?.parse()?)?;
+                        pad.push(quote!(#bytes * 8));
+                    }
+                    // 其他 deku 属性不影响长度，跳过其取值
+                    _ => skip_value(&meta)?,
+                }
+                Ok(())
+            })?;
+        }
+    }
+    if skip {
+        return Ok(quote!(0));
+    }
+    let size = size.unwrap_or_else(|| quote!(<#ty as ::rm_referee_protocol::WireSize>::BITS));
+    Ok(quote!((#size #(+ #pad)*)))
+}
+
+// 接受整数字面量或 deku 习惯的字符串形式（bits = "4"）
+fn lit_usize(expr: &Expr) -> Result<usize> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse(),
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => lit.parse::<LitInt>()?.base10_parse(),
+        _ => Err(Error::new_spanned(expr, "expected an integer literal")),
+    }
+}
+
+// 借助 serde 的 remote 派生生成 Serialize / Deserialize，无需用户在结构体上另加派生
+#[cfg(feature = "serde")]
+fn serde_impls(input: &DeriveInput, fields: &Fields) -> Result<TokenStream2> {
+    use quote::format_ident;
+    use syn::LitStr;
+
+    let ident = &input.ident;
+    let shadow = format_ident!("__{}RobotMessageSerde", ident);
+    let remote = LitStr::new(&ident.to_string(), ident.span());
+    let private = quote!(::rm_referee_protocol::__private);
+    let serde_crate = LitStr::new("::rm_referee_protocol::__private::serde", ident.span());
+    let body = match fields {
+        Fields::Named(named) => {
+            let fields = named.named.iter().filter(|f| !is_temp(f)).map(|f| {
+                let (name, ty) = (&f.ident, &f.ty);
+                quote!(#name: #ty)
+            });
+            quote!({ #(#fields,)* })
+        }
+        Fields::Unnamed(unnamed) => {
+            let fields = unnamed
+                .unnamed
+                .iter()
+                .filter(|f| !is_temp(f))
+                .map(|f| &f.ty);
+            quote!((#(#fields,)*);)
+        }
+        Fields::Unit => quote!(;),
+    };
+    Ok(quote! {
+        const _: () = {
+            #[allow(dead_code)]
+            #[derive(#private::serde::Serialize, #private::serde::Deserialize)]
+            #[serde(crate = #serde_crate, remote = #remote)]
+            struct #shadow #body
+
+            impl #private::serde::Serialize for #ident {
+                fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+                where
+                    S: #private::serde::Serializer,
+                {
+                    #shadow::serialize(self, serializer)
+                }
+            }
+
+            impl<'de> #private::serde::Deserialize<'de> for #ident {
+                fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+                where
+                    D: #private::serde::Deserializer<'de>,
+                {
+                    #shadow::deserialize(deserializer)
+                }
+            }
+        };
+    })
+}
+
+#[cfg(not(feature = "serde"))]
+fn serde_impls(_input: &DeriveInput, _fields: &Fields) -> Result<TokenStream2> {
+    Ok(TokenStream2::new())
+}
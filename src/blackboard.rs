@@ -0,0 +1,284 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
+use core::time::Duration;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::robot_interaction::{MAX_INTERACTION_CONTENT_LEN, MAX_INTERACTION_RATE_HZ};
+use crate::robot_message::check_id;
+use crate::{EndpointId, MessageError, RefereeFrameCmdData};
+
+// 黑板同步默认使用的子内容 ID
+pub const BLACKBOARD_DATA_CMD_ID: u16 = 0x02F2;
+// 单个键值的最大字节数
+pub const BLACKBOARD_MAX_VALUE_LEN: usize = 32;
+// 条目头：键、版本号（小端 2 字节）、长度
+const ENTRY_HEADER_LEN: usize = 4;
+const FLAG_BEACON: u8 = 0x01;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BlackboardError {
+    // 未通过 with_key 声明的键
+    UnknownKey(u8),
+    // 只有键的所有者可以写入
+    NotOwner(u8),
+    TooLong(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Entry {
+    // 所有者写入该值时的会话号
+    session: u8,
+    version: u16,
+    value: Vec<u8>,
+}
+
+// 对某个对端待发送的本机键
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct PeerQueue {
+    delta: BTreeSet<u8>,
+    beacon: BTreeSet<u8>,
+}
+
+// 队伍黑板：每个键归属一台机器人，只有所有者可以写入。
+// 本机键的变化以增量发送给各对端，并周期性发送全部本机键作为信标，
+// 使丢帧或重启后的对端收敛到一致状态。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Blackboard {
+    local: EndpointId,
+    session: u8,
+    data_cmd_id: u16,
+    min_interval: Duration,
+    last_send: Option<Duration>,
+    beacon_interval: Option<Duration>,
+    next_beacon: Duration,
+    owners: BTreeMap<u8, EndpointId>,
+    entries: BTreeMap<u8, Entry>,
+    peers: BTreeMap<u16, (EndpointId, PeerQueue)>,
+    cursor: Option<u16>,
+}
+
+impl Blackboard {
+    // session 为本机会话号，每次启动须不同（同 ReliableLink::new）；
+    // rate_hz 超过协议上限时按上限处理
+    pub fn new(local: EndpointId, session: u8, rate_hz: u32) -> Self {
+        let rate = rate_hz.clamp(1, MAX_INTERACTION_RATE_HZ);
+        Self {
+            local,
+            session,
+            data_cmd_id: BLACKBOARD_DATA_CMD_ID,
+            min_interval: Duration::from_secs(1) / rate,
+            last_send: None,
+            beacon_interval: None,
+            next_beacon: Duration::ZERO,
+            owners: BTreeMap::new(),
+            entries: BTreeMap::new(),
+            peers: BTreeMap::new(),
+            cursor: None,
+        }
+    }
+
+    // data_cmd_id 须在 0x0200~0x02FF
+    pub fn with_data_cmd_id(mut self, data_cmd_id: u16) -> Result<Self, MessageError> {
+        check_id(data_cmd_id)?;
+        self.data_cmd_id = data_cmd_id;
+        Ok(self)
+    }
+
+    // 每隔 interval 向各对端重发全部本机键
+    pub fn with_beacon(mut self, interval: Duration) -> Self {
+        self.beacon_interval = Some(interval);
+        self
+    }
+
+    pub fn with_peer(mut self, peer: EndpointId) -> Self {
+        if peer != self.local {
+            self.peers
+                .entry(peer as u16)
+                .or_insert_with(|| (peer, PeerQueue::default()));
+        }
+        self
+    }
+
+    // 声明键及其所有者，所有机器人应使用相同的声明
+    pub fn with_key(mut self, key: u8, owner: EndpointId) -> Self {
+        self.owners.insert(key, owner);
+        self
+    }
+
+    pub fn local(&self) -> EndpointId {
+        self.local
+    }
+
+    pub fn owner(&self, key: u8) -> Option<EndpointId> {
+        self.owners.get(&key).copied()
+    }
+
+    // 写入本机拥有的键，值变化时返回 true 并排队增量
+    pub fn set(&mut self, key: u8, value: &[u8]) -> Result<bool, BlackboardError> {
+        match self.owner(key) {
+            None => return Err(BlackboardError::UnknownKey(key)),
+            Some(owner) if owner != self.local => return Err(BlackboardError::NotOwner(key)),
+            Some(_) => {}
+        }
+        if value.len() > BLACKBOARD_MAX_VALUE_LEN {
+            return Err(BlackboardError::TooLong(value.len()));
+        }
+        let version = match self.entries.get(&key) {
+            Some(entry) if entry.value == value => return Ok(false),
+            Some(entry) => entry.version.wrapping_add(1),
+            None => 0,
+        };
+        self.entries.insert(
+            key,
+            Entry {
+                session: self.session,
+                version,
+                value: value.to_vec(),
+            },
+        );
+        for (_, queue) in self.peers.values_mut() {
+            queue.delta.insert(key);
+        }
+        Ok(true)
+    }
+
+    pub fn get(&self, key: u8) -> Option<&[u8]> {
+        self.entries.get(&key).map(|e| e.value.as_slice())
+    }
+
+    pub fn version(&self, key: u8) -> Option<u16> {
+        self.entries.get(&key).map(|e| e.version)
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = (u8, &[u8])> {
+        self.entries.iter().map(|(k, e)| (*k, e.value.as_slice()))
+    }
+
+    // 到达发送时隙时返回下一帧，各对端轮流发送；信标优先于增量
+    pub fn poll(&mut self, now: Duration) -> Option<RefereeFrameCmdData> {
+        if let Some(interval) = self.beacon_interval
+            && now >= self.next_beacon
+        {
+            self.next_beacon = now + interval;
+            let owned = self.owned_keys();
+            for (_, queue) in self.peers.values_mut() {
+                queue.beacon.extend(owned.iter().copied());
+            }
+        }
+        if self
+            .last_send
+            .is_some_and(|t| now.saturating_sub(t) < self.min_interval)
+        {
+            return None;
+        }
+        let ready = |q: &PeerQueue| !q.delta.is_empty() || !q.beacon.is_empty();
+        let next = self
+            .peers
+            .iter()
+            .filter(|(id, (_, q))| Some(**id) > self.cursor && ready(q))
+            .chain(self.peers.iter().filter(|(_, (_, q))| ready(q)))
+            .map(|(id, _)| *id)
+            .next()?;
+        self.cursor = Some(next);
+        let (peer, queue) = self.peers.get_mut(&next)?;
+        let peer = *peer;
+        let beacon = !queue.beacon.is_empty();
+        let keys = if beacon {
+            &mut queue.beacon
+        } else {
+            &mut queue.delta
+        };
+        // 帧头：标志、发送方会话号
+        let mut user_data = alloc::vec![if beacon { FLAG_BEACON } else { 0 }, self.session];
+        let mut sent = Vec::new();
+        for key in keys.iter() {
+            let Some(entry) = self.entries.get(key) else {
+                sent.push(*key);
+                continue;
+            };
+            if user_data.len() + ENTRY_HEADER_LEN + entry.value.len() > MAX_INTERACTION_CONTENT_LEN
+            {
+                break;
+            }
+            user_data.push(*key);
+            user_data.extend_from_slice(&entry.version.to_le_bytes());
+            user_data.push(entry.value.len() as u8);
+            user_data.extend_from_slice(&entry.value);
+            sent.push(*key);
+        }
+        // 发出的值已是最新，无需再作为增量发送
+        for key in &sent {
+            queue.beacon.remove(key);
+            queue.delta.remove(key);
+        }
+        self.last_send = Some(now);
+        Some(RefereeFrameCmdData::RobotInteractionData {
+            data_cmd_id: self.data_cmd_id,
+            sender_id: self.local,
+            receiver_id: peer,
+            user_data,
+        })
+    }
+
+    // 处理收到的 0x0301 数据，返回值发生变化的键。
+    // 非所有者发来的键被忽略；同一会话内只接受更新的版本，延迟到达的旧信标不会回滚，
+    // 所有者重启（会话号变化）后版本号归零的值直接覆盖
+    pub fn receive(&mut self, cmd_data: &RefereeFrameCmdData) -> Vec<u8> {
+        let mut changed = Vec::new();
+        let RefereeFrameCmdData::RobotInteractionData {
+            data_cmd_id,
+            sender_id,
+            receiver_id,
+            user_data,
+        } = cmd_data
+        else {
+            return changed;
+        };
+        if *data_cmd_id != self.data_cmd_id || *receiver_id != self.local {
+            return changed;
+        }
+        let Some((&[_flags, session], mut rest)) = user_data.split_first_chunk() else {
+            return changed;
+        };
+        while let Some((&[key, v0, v1, len], tail)) = rest.split_first_chunk() {
+            let Some((value, tail)) = tail.split_at_checked(usize::from(len)) else {
+                break;
+            };
+            rest = tail;
+            if self.owner(key) != Some(*sender_id) {
+                continue;
+            }
+            let version = u16::from_le_bytes([v0, v1]);
+            if let Some(e) = self.entries.get_mut(&key) {
+                // 同一会话内只接受更新的版本；会话号不同说明所有者已重启
+                if e.session == session && version.wrapping_sub(e.version) as i16 <= 0 {
+                    continue;
+                }
+                if e.version == version && e.value == value {
+                    e.session = session;
+                    continue;
+                }
+            }
+            self.entries.insert(
+                key,
+                Entry {
+                    session,
+                    version,
+                    value: value.to_vec(),
+                },
+            );
+            changed.push(key);
+        }
+        changed
+    }
+
+    fn owned_keys(&self) -> Vec<u8> {
+        self.owners
+            .iter()
+            .filter(|(key, owner)| **owner == self.local && self.entries.contains_key(key))
+            .map(|(key, _)| *key)
+            .collect()
+    }
+}
@@ -0,0 +1,121 @@
+use alloc::string::String;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{EndpointId, RefereeFrameCmdData};
+
+// 0x0308 字符区字节数：UTF-16（小端）编码，最多 15 个码元
+pub const CUSTOM_INFO_LEN: usize = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CustomInfoError {
+    // 发送方须为机器人
+    SenderNotRobot(EndpointId),
+    // 接收方须为选手端
+    ReceiverNotClient(EndpointId),
+    // 发送方与接收方不属于同一方
+    CrossTeam,
+    // 不是 0x0308 数据
+    NotCustomInfo,
+    // 字符区不是合法的 UTF-16
+    InvalidUtf16,
+}
+
+// 0x0308 己方机器人发往己方选手端小地图的文字消息
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CustomInfo {
+    sender_id: EndpointId,
+    receiver_id: EndpointId,
+    text: String,
+}
+
+impl CustomInfo {
+    // 超出 30 字节的部分按字符截断，不会拆开代理对
+    pub fn text(
+        sender_id: EndpointId,
+        receiver_id: EndpointId,
+        text: &str,
+    ) -> Result<Self, CustomInfoError> {
+        check_endpoints(sender_id, receiver_id)?;
+        let mut units = 0;
+        let text = text
+            .chars()
+            .take_while(|c| {
+                units += c.len_utf16();
+                units * 2 <= CUSTOM_INFO_LEN
+            })
+            .collect();
+        Ok(Self {
+            sender_id,
+            receiver_id,
+            text,
+        })
+    }
+
+    pub fn from_cmd_data(cmd_data: &RefereeFrameCmdData) -> Result<Self, CustomInfoError> {
+        let RefereeFrameCmdData::CustomInfo {
+            sender_id,
+            receiver_id,
+            data,
+        } = cmd_data
+        else {
+            return Err(CustomInfoError::NotCustomInfo);
+        };
+        check_endpoints(*sender_id, *receiver_id)?;
+        // 字符串以第一个 0 码元结束
+        let units = data
+            .chunks_exact(2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+            .take_while(|u| *u != 0);
+        let text = char::decode_utf16(units)
+            .collect::<Result<String, _>>()
+            .map_err(|_| CustomInfoError::InvalidUtf16)?;
+        Ok(Self {
+            sender_id: *sender_id,
+            receiver_id: *receiver_id,
+            text,
+        })
+    }
+
+    // 编码为 UTF-16 小端并以 0 填充
+    pub fn to_cmd_data(&self) -> RefereeFrameCmdData {
+        let mut data = [0; CUSTOM_INFO_LEN];
+        for (slot, unit) in data.chunks_exact_mut(2).zip(self.text.encode_utf16()) {
+            slot.copy_from_slice(&unit.to_le_bytes());
+        }
+        RefereeFrameCmdData::CustomInfo {
+            sender_id: self.sender_id,
+            receiver_id: self.receiver_id,
+            data,
+        }
+    }
+
+    pub fn sender_id(&self) -> EndpointId {
+        self.sender_id
+    }
+
+    pub fn receiver_id(&self) -> EndpointId {
+        self.receiver_id
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+}
+
+fn check_endpoints(sender_id: EndpointId, receiver_id: EndpointId) -> Result<(), CustomInfoError> {
+    let robot = sender_id
+        .robot_id()
+        .ok_or(CustomInfoError::SenderNotRobot(sender_id))?;
+    // 选手端 ID：红方 0x0101~0x0106，蓝方 0x0165~0x016A
+    let client = receiver_id as u16;
+    if !(0x0101..=0x016A).contains(&client) {
+        return Err(CustomInfoError::ReceiverNotClient(receiver_id));
+    }
+    if robot.is_red() != (client < 0x0165) {
+        return Err(CustomInfoError::CrossTeam);
+    }
+    Ok(())
+}
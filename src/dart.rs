@@ -0,0 +1,130 @@
+use core::time::Duration;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{DartLaunchGate, DartSelectionTarget, DartTarget, GameProgress, RefereeFrameCmdData};
+
+// 飞镖站综合状态（由 0x0001 / 0x0105 / 0x020A 推导）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DartStationState {
+    // 比赛未进行，或尚未收到发射口状态
+    Inactive,
+    // 发射口关闭
+    Closed,
+    // 发射口正在开启或关闭
+    Transitioning,
+    // 发射口已开启，且仍有剩余发射时间
+    Ready,
+    // 发射口已开启，但剩余发射时间为 0
+    Expired,
+}
+
+// 飞镖站状态机：持续喂入裁判系统数据，查询当前能否发射、窗口剩余时间与所选目标
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DartStation {
+    game_progress: Option<GameProgress>,
+    stage_remain_time: u16,
+    gate: Option<DartLaunchGate>,
+    target_change_time: u16,
+    latest_launch_cmd_time: u16,
+    remaining_time: u8,
+    selected_target: Option<DartSelectionTarget>,
+    last_hit_target: Option<DartTarget>,
+    opponent_hit_count: u8,
+}
+
+impl DartStation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 喂入一条命令数据；与飞镖站相关时更新状态并返回 true
+    pub fn update(&mut self, data: &RefereeFrameCmdData) -> bool {
+        match *data {
+            RefereeFrameCmdData::GameStatus {
+                game_progress,
+                stage_remain_time,
+                ..
+            } => {
+                self.game_progress = Some(game_progress);
+                self.stage_remain_time = stage_remain_time;
+            }
+            RefereeFrameCmdData::DartInfo {
+                dart_remaining_time,
+                dart_last_hit_target,
+                opponent_target_hit_count,
+                dart_selected_target,
+            } => {
+                self.remaining_time = dart_remaining_time;
+                self.last_hit_target = Some(dart_last_hit_target);
+                self.opponent_hit_count = opponent_target_hit_count;
+                self.selected_target = Some(dart_selected_target);
+            }
+            RefereeFrameCmdData::DartClientCmd {
+                dart_launch_opening_status,
+                target_change_time,
+                latest_launch_cmd_time,
+            } => {
+                self.gate = Some(dart_launch_opening_status);
+                self.target_change_time = target_change_time;
+                self.latest_launch_cmd_time = latest_launch_cmd_time;
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    pub fn state(&self) -> DartStationState {
+        if self.game_progress != Some(GameProgress::InProgress) {
+            return DartStationState::Inactive;
+        }
+        match self.gate {
+            None => DartStationState::Inactive,
+            Some(DartLaunchGate::Closed) => DartStationState::Closed,
+            Some(DartLaunchGate::Transitioning) => DartStationState::Transitioning,
+            Some(DartLaunchGate::Open) if self.remaining_time > 0 => DartStationState::Ready,
+            Some(DartLaunchGate::Open) => DartStationState::Expired,
+        }
+    }
+
+    pub fn launch_permitted(&self) -> bool {
+        self.state() == DartStationState::Ready
+    }
+
+    // 发射窗口剩余时间（仅在 Ready 时有值）
+    pub fn launch_window_remaining(&self) -> Option<Duration> {
+        self.launch_permitted()
+            .then(|| Duration::from_secs(self.remaining_time.into()))
+    }
+
+    pub fn gate(&self) -> Option<DartLaunchGate> {
+        self.gate
+    }
+
+    pub fn selected_target(&self) -> Option<DartSelectionTarget> {
+        self.selected_target
+    }
+
+    pub fn last_hit_target(&self) -> Option<DartTarget> {
+        self.last_hit_target
+    }
+
+    pub fn opponent_hit_count(&self) -> u8 {
+        self.opponent_hit_count
+    }
+
+    // 距离选手端最近一次确认发射指令的时间；0x020A 中该字段为比赛剩余时间，初始值 0 表示从未发送
+    pub fn since_launch_cmd(&self) -> Option<Duration> {
+        Self::elapsed_since(self.latest_launch_cmd_time, self.stage_remain_time)
+    }
+
+    // 距离最近一次切换目标的时间；语义同上
+    pub fn since_target_change(&self) -> Option<Duration> {
+        Self::elapsed_since(self.target_change_time, self.stage_remain_time)
+    }
+
+    fn elapsed_since(mark: u16, stage_remain_time: u16) -> Option<Duration> {
+        (mark != 0).then(|| Duration::from_secs(mark.saturating_sub(stage_remain_time).into()))
+    }
+}
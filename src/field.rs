@@ -0,0 +1,129 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::GameType;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FieldError {
+    // 坐标不是有限值
+    NotFinite,
+    // 超出线上编码的取值范围
+    OutOfRange,
+    // 超出当前比赛类型的场地
+    OutOfField,
+    // 该比赛类型没有对抗场地尺寸
+    UnknownField(GameType),
+}
+
+// 各比赛类型的场地长宽（米），原点为红方一侧场地左下角
+pub fn field_size(game_type: GameType) -> Option<(f32, f32)> {
+    match game_type {
+        GameType::RMUC => Some((28.0, 15.0)),
+        GameType::RMUL3v3 | GameType::RMULInfantry => Some((12.0, 8.0)),
+        GameType::RMUA => Some((8.08, 4.48)),
+        GameType::RMUT => None,
+    }
+}
+
+// 场地坐标（米）。各协议使用不同单位：0x0203 / 0x020B / 0x0303 为 f32 米，
+// 0x0305 为 u16 厘米，0x0307 为 u16 分米起点与 i8 分米增量；
+// 线上整数编码转换为 FieldPoint 再转回是无损的。
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FieldPoint {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl FieldPoint {
+    pub const ORIGIN: Self = Self { x: 0.0, y: 0.0 };
+
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    pub fn from_cm(x: u16, y: u16) -> Self {
+        Self::new(f32::from(x) / 100.0, f32::from(y) / 100.0)
+    }
+
+    pub fn from_dm(x: u16, y: u16) -> Self {
+        Self::new(f32::from(x) / 10.0, f32::from(y) / 10.0)
+    }
+
+    // 0x0305 小地图坐标，四舍五入到厘米
+    pub fn to_cm(self) -> Result<(u16, u16), FieldError> {
+        Ok((to_u16(self.x, 100.0)?, to_u16(self.y, 100.0)?))
+    }
+
+    // 0x0307 路径起点，四舍五入到分米
+    pub fn to_dm(self) -> Result<(u16, u16), FieldError> {
+        Ok((to_u16(self.x, 10.0)?, to_u16(self.y, 10.0)?))
+    }
+
+    // 0x0307 路径增量：到 to 的分米差，须在 i8 范围内
+    pub fn dm_delta_to(self, to: Self) -> Result<(i8, i8), FieldError> {
+        let (x0, y0) = self.to_dm()?;
+        let (x1, y1) = to.to_dm()?;
+        let delta = |a: u16, b: u16| {
+            i8::try_from(i32::from(b) - i32::from(a)).map_err(|_| FieldError::OutOfRange)
+        };
+        Ok((delta(x0, x1)?, delta(y0, y1)?))
+    }
+
+    pub fn offset_dm(self, (dx, dy): (i8, i8)) -> Self {
+        Self::new(self.x + f32::from(dx) / 10.0, self.y + f32::from(dy) / 10.0)
+    }
+
+    pub fn in_field(self, game_type: GameType) -> Result<Self, FieldError> {
+        if !self.x.is_finite() || !self.y.is_finite() {
+            return Err(FieldError::NotFinite);
+        }
+        let (length, width) = field_size(game_type).ok_or(FieldError::UnknownField(game_type))?;
+        if (0.0..=length).contains(&self.x) && (0.0..=width).contains(&self.y) {
+            Ok(self)
+        } else {
+            Err(FieldError::OutOfField)
+        }
+    }
+
+    // 场地关于中心对称：红方坐标与蓝方视角坐标互换
+    pub fn mirrored(self, game_type: GameType) -> Result<Self, FieldError> {
+        let (length, width) = field_size(game_type).ok_or(FieldError::UnknownField(game_type))?;
+        Ok(Self::new(length - self.x, width - self.y))
+    }
+}
+
+impl From<(f32, f32)> for FieldPoint {
+    fn from((x, y): (f32, f32)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<FieldPoint> for (f32, f32) {
+    fn from(point: FieldPoint) -> Self {
+        (point.x, point.y)
+    }
+}
+
+// 四舍五入到 1 / scale 米的整数
+fn round_scaled(value: f32, scale: f32) -> Result<i32, FieldError> {
+    if !value.is_finite() {
+        return Err(FieldError::NotFinite);
+    }
+    let scaled = f64::from(value) * f64::from(scale);
+    let rounded = if scaled >= 0.0 {
+        scaled + 0.5
+    } else {
+        scaled - 0.5
+    };
+    if rounded > f64::from(i32::MIN) - 1.0 && rounded < f64::from(i32::MAX) + 1.0 {
+        Ok(rounded as i32)
+    } else {
+        Err(FieldError::OutOfRange)
+    }
+}
+
+fn to_u16(value: f32, scale: f32) -> Result<u16, FieldError> {
+    u16::try_from(round_scaled(value, scale)?).map_err(|_| FieldError::OutOfRange)
+}
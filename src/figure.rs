@@ -0,0 +1,607 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    FigureColor, FigureOperateType, FigureType, InteractionFigure, RobotInteractionUserData,
+    ScreenPoint,
+};
+
+// 各图形类型对 details_a ~ details_e 的语义（附录 0x0101 图形数据表）
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FigureShape {
+    // 起点为 start，终点为 end
+    Line {
+        end: ScreenPoint,
+    },
+    // 起点与对角顶点
+    Rectangle {
+        end: ScreenPoint,
+    },
+    // 圆心为 start
+    Circle {
+        radius: u16,
+    },
+    // 圆心为 start，x / y 半轴长度
+    Ellipse {
+        radius_x: u16,
+        radius_y: u16,
+    },
+    // 圆心为 start，起止角度 0-360（顺时针，0 为正上方）
+    Arc {
+        start_angle: u16,
+        end_angle: u16,
+        radius_x: u16,
+        radius_y: u16,
+    },
+    // 字体大小与显示值（线上为 ×1000 后的 int32）
+    Float {
+        font_size: u16,
+        value: f32,
+    },
+    Int {
+        font_size: u16,
+        value: i32,
+    },
+    // 字体大小与字符长度；字符内容随 0x0110 单独发送
+    Text {
+        font_size: u16,
+        length: u16,
+    },
+}
+
+impl FigureShape {
+    pub fn figure_type(&self) -> FigureType {
+        match self {
+            FigureShape::Line { .. } => FigureType::Line,
+            FigureShape::Rectangle { .. } => FigureType::Rectangle,
+            FigureShape::Circle { .. } => FigureType::Circle,
+            FigureShape::Ellipse { .. } => FigureType::Ellipse,
+            FigureShape::Arc { .. } => FigureType::Arc,
+            FigureShape::Float { .. } => FigureType::Float,
+            FigureShape::Int { .. } => FigureType::Int,
+            FigureShape::Text { .. } => FigureType::Char,
+        }
+    }
+}
+
+// 不含操作类型的图形描述：名称、图层、颜色、线宽、起点与具体形状
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Figure {
+    pub name: [u8; 3],
+    pub layer: u8,
+    pub color: FigureColor,
+    pub width: u16,
+    pub start: ScreenPoint,
+    pub shape: FigureShape,
+}
+
+impl Figure {
+    pub fn line(
+        name: [u8; 3],
+        layer: u8,
+        color: FigureColor,
+        width: u16,
+        start: ScreenPoint,
+        end: ScreenPoint,
+    ) -> Self {
+        Self {
+            name,
+            layer,
+            color,
+            width,
+            start,
+            shape: FigureShape::Line { end },
+        }
+    }
+
+    pub fn rect(
+        name: [u8; 3],
+        layer: u8,
+        color: FigureColor,
+        width: u16,
+        start: ScreenPoint,
+        end: ScreenPoint,
+    ) -> Self {
+        Self {
+            name,
+            layer,
+            color,
+            width,
+            start,
+            shape: FigureShape::Rectangle { end },
+        }
+    }
+
+    pub fn circle(
+        name: [u8; 3],
+        layer: u8,
+        color: FigureColor,
+        width: u16,
+        center: ScreenPoint,
+        radius: u16,
+    ) -> Self {
+        Self {
+            name,
+            layer,
+            color,
+            width,
+            start: center,
+            shape: FigureShape::Circle { radius },
+        }
+    }
+
+    pub fn ellipse(
+        name: [u8; 3],
+        layer: u8,
+        color: FigureColor,
+        width: u16,
+        center: ScreenPoint,
+        radii: (u16, u16),
+    ) -> Self {
+        Self {
+            name,
+            layer,
+            color,
+            width,
+            start: center,
+            shape: FigureShape::Ellipse {
+                radius_x: radii.0,
+                radius_y: radii.1,
+            },
+        }
+    }
+
+    pub fn arc(
+        name: [u8; 3],
+        layer: u8,
+        color: FigureColor,
+        width: u16,
+        center: ScreenPoint,
+        angles: (u16, u16),
+        radii: (u16, u16),
+    ) -> Self {
+        Self {
+            name,
+            layer,
+            color,
+            width,
+            start: center,
+            shape: FigureShape::Arc {
+                start_angle: angles.0,
+                end_angle: angles.1,
+                radius_x: radii.0,
+                radius_y: radii.1,
+            },
+        }
+    }
+
+    pub fn int(
+        name: [u8; 3],
+        layer: u8,
+        color: FigureColor,
+        font_size: u16,
+        width: u16,
+        pos: ScreenPoint,
+        value: i32,
+    ) -> Self {
+        Self {
+            name,
+            layer,
+            color,
+            width,
+            start: pos,
+            shape: FigureShape::Int { font_size, value },
+        }
+    }
+
+    pub fn float(
+        name: [u8; 3],
+        layer: u8,
+        color: FigureColor,
+        font_size: u16,
+        width: u16,
+        pos: ScreenPoint,
+        value: f32,
+    ) -> Self {
+        Self {
+            name,
+            layer,
+            color,
+            width,
+            start: pos,
+            shape: FigureShape::Float { font_size, value },
+        }
+    }
+
+    // 字符图形的配置部分；字符内容见 0x0110 CustomCharacter
+    pub fn text(
+        name: [u8; 3],
+        layer: u8,
+        color: FigureColor,
+        font_size: u16,
+        width: u16,
+        pos: ScreenPoint,
+        length: u16,
+    ) -> Self {
+        Self {
+            name,
+            layer,
+            color,
+            width,
+            start: pos,
+            shape: FigureShape::Text { font_size, length },
+        }
+    }
+
+    // 按给定操作类型编码为线上图形配置；线宽、半径、角度、字号等标量超出位宽时饱和到最大值，
+    // 需要显式报错时使用 try_to_interaction
+    pub fn to_interaction(&self, operate_type: FigureOperateType) -> InteractionFigure {
+        let (details_a, details_b, details_c, details_d, details_e) = match self.shape {
+            FigureShape::Line { end } | FigureShape::Rectangle { end } => {
+                (0, 0, 0, end.x(), end.y())
+            }
+            FigureShape::Circle { radius } => (0, 0, radius, 0, 0),
+            FigureShape::Ellipse { radius_x, radius_y } => (0, 0, 0, radius_x, radius_y),
+            FigureShape::Arc {
+                start_angle,
+                end_angle,
+                radius_x,
+                radius_y,
+            } => (start_angle, end_angle, 0, radius_x, radius_y),
+            FigureShape::Float { font_size, value } => {
+                // 超出 int32 范围时饱和，NaN 显示为 0；需要显式报错时使用 set_float_value
+                let milli = float_to_milli(value).unwrap_or(if value > 0.0 {
+                    i32::MAX
+                } else if value < 0.0 {
+                    i32::MIN
+                } else {
+                    0
+                });
+                let (c, d, e) = split_i32(milli);
+                (font_size, 0, c, d, e)
+            }
+            FigureShape::Int { font_size, value } => {
+                let (c, d, e) = split_i32(value);
+                (font_size, 0, c, d, e)
+            }
+            FigureShape::Text { font_size, length } => (font_size, length, 0, 0, 0),
+        };
+        InteractionFigure {
+            figure_name: self.name,
+            operate_type,
+            figure_type: self.shape.figure_type(),
+            layer: self.layer.min(15),
+            color: self.color,
+            details_a: details_a.min(BITS9_MAX),
+            details_b: details_b.min(BITS9_MAX),
+            width: self.width.min(BITS10_MAX),
+            start_x: self.start.x(),
+            start_y: self.start.y(),
+            details_c: details_c.min(BITS10_MAX),
+            details_d: details_d.min(BITS11_MAX),
+            details_e: details_e.min(BITS11_MAX),
+        }
+    }
+
+    // 与 to_interaction 相同，但图层、线宽、半径、字号等超出位宽、圆弧角度超过 360
+    // 或浮点数超出 int32 范围时返回对应字段的错误而不是饱和
+    pub fn try_to_interaction(
+        &self,
+        operate_type: FigureOperateType,
+    ) -> Result<InteractionFigure, FigureError> {
+        let fits = |value: u16, max: u16, err: FigureError| {
+            if value <= max { Ok(()) } else { Err(err) }
+        };
+        fits(self.layer.into(), 15, FigureError::Layer)?;
+        fits(self.width, BITS10_MAX, FigureError::Width)?;
+        match self.shape {
+            FigureShape::Line { .. } | FigureShape::Rectangle { .. } => {}
+            FigureShape::Circle { radius } => fits(radius, BITS10_MAX, FigureError::Radius)?,
+            FigureShape::Ellipse { radius_x, radius_y } => {
+                fits(radius_x, BITS11_MAX, FigureError::Radius)?;
+                fits(radius_y, BITS11_MAX, FigureError::Radius)?;
+            }
+            FigureShape::Arc {
+                start_angle,
+                end_angle,
+                radius_x,
+                radius_y,
+            } => {
+                fits(start_angle, 360, FigureError::Angle)?;
+                fits(end_angle, 360, FigureError::Angle)?;
+                fits(radius_x, BITS11_MAX, FigureError::Radius)?;
+                fits(radius_y, BITS11_MAX, FigureError::Radius)?;
+            }
+            FigureShape::Float { font_size, value } => {
+                fits(font_size, BITS9_MAX, FigureError::FontSize)?;
+                float_to_milli(value).ok_or(FigureError::Value)?;
+            }
+            FigureShape::Int { font_size, .. } => {
+                fits(font_size, BITS9_MAX, FigureError::FontSize)?
+            }
+            FigureShape::Text { font_size, length } => {
+                fits(font_size, BITS9_MAX, FigureError::FontSize)?;
+                fits(length, BITS9_MAX, FigureError::Length)?;
+            }
+        }
+        Ok(self.to_interaction(operate_type))
+    }
+}
+
+// 解码线上图形配置；起点或终点在屏幕外时返回该坐标
+impl TryFrom<&InteractionFigure> for Figure {
+    type Error = FigureError;
+
+    fn try_from(fig: &InteractionFigure) -> Result<Self, FigureError> {
+        Ok(Self {
+            name: fig.figure_name,
+            layer: fig.layer,
+            color: fig.color,
+            width: fig.width,
+            start: on_screen(fig.start_x, fig.start_y)?,
+            shape: fig.shape()?,
+        })
+    }
+}
+
+fn on_screen(x: u16, y: u16) -> Result<ScreenPoint, FigureError> {
+    ScreenPoint::new(x, y).map_err(|_| FigureError::OffScreen { x, y })
+}
+
+impl InteractionFigure {
+    // 按 figure_type 解释 details_a ~ details_e；直线、矩形终点在屏幕外时返回该坐标
+    pub fn shape(&self) -> Result<FigureShape, FigureError> {
+        Ok(match self.figure_type {
+            FigureType::Line => FigureShape::Line {
+                end: on_screen(self.details_d, self.details_e)?,
+            },
+            FigureType::Rectangle => FigureShape::Rectangle {
+                end: on_screen(self.details_d, self.details_e)?,
+            },
+            FigureType::Circle => FigureShape::Circle {
+                radius: self.details_c,
+            },
+            FigureType::Ellipse => FigureShape::Ellipse {
+                radius_x: self.details_d,
+                radius_y: self.details_e,
+            },
+            FigureType::Arc => FigureShape::Arc {
+                start_angle: self.details_a,
+                end_angle: self.details_b,
+                radius_x: self.details_d,
+                radius_y: self.details_e,
+            },
+            FigureType::Float => FigureShape::Float {
+                font_size: self.details_a,
+                value: self.float_value(),
+            },
+            FigureType::Int => FigureShape::Int {
+                font_size: self.details_a,
+                value: self.int_value(),
+            },
+            FigureType::Char => FigureShape::Text {
+                font_size: self.details_a,
+                length: self.details_b,
+            },
+        })
+    }
+
+    // Int / Float 图形的 int32 原始值
+    pub fn int_value(&self) -> i32 {
+        join_i32(self.details_c, self.details_d, self.details_e)
+    }
+
+    pub fn set_int_value(&mut self, value: i32) {
+        (self.details_c, self.details_d, self.details_e) = split_i32(value);
+    }
+
+    // Float 图形数值：线上为 ×1000 后四舍五入的 int32
+    pub fn float_value(&self) -> f32 {
+        (f64::from(self.int_value()) / FLOAT_SCALE) as f32
+    }
+
+    // 超出 ±2147483.647 或为 NaN 时不修改并返回 false
+    pub fn set_float_value(&mut self, value: f32) -> bool {
+        match float_to_milli(value) {
+            Some(milli) => {
+                self.set_int_value(milli);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+const FLOAT_SCALE: f64 = 1000.0;
+
+const BITS9_MAX: u16 = (1 << 9) - 1;
+const BITS10_MAX: u16 = (1 << 10) - 1;
+const BITS11_MAX: u16 = (1 << 11) - 1;
+
+// f32 → ×1000 并四舍五入的 int32；超出范围或 NaN 返回 None
+pub fn float_to_milli(value: f32) -> Option<i32> {
+    let scaled = f64::from(value) * FLOAT_SCALE;
+    let rounded = if scaled >= 0.0 {
+        scaled + 0.5
+    } else {
+        scaled - 0.5
+    };
+    // NaN 的比较均为 false
+    if rounded > f64::from(i32::MIN) - 1.0 && rounded < f64::from(i32::MAX) + 1.0 {
+        Some(rounded as i32)
+    } else {
+        None
+    }
+}
+
+// int32 按 details_c（低 10 bit）/ details_d（中 11 bit）/ details_e（高 11 bit）拆分
+fn split_i32(value: i32) -> (u16, u16, u16) {
+    let v = value as u32;
+    (
+        (v & 0x3FF) as u16,
+        ((v >> 10) & 0x7FF) as u16,
+        ((v >> 21) & 0x7FF) as u16,
+    )
+}
+
+fn join_i32(c: u16, d: u16, e: u16) -> i32 {
+    (u32::from(c & 0x3FF) | u32::from(d & 0x7FF) << 10 | u32::from(e & 0x7FF) << 21) as i32
+}
+
+// 图形字段超出线上位宽或取值范围，变体指明出错的字段
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FigureError {
+    // 图层超过 15
+    Layer,
+    // 线宽超过 10 位
+    Width,
+    // 圆半径超过 10 位，或椭圆、圆弧半轴超过 11 位
+    Radius,
+    // 圆弧起止角度超过 360
+    Angle,
+    // 字号超过 9 位
+    FontSize,
+    // 字符长度超过 9 位
+    Length,
+    // 浮点数为 NaN 或 ×1000 后超出 int32
+    Value,
+    // 解码得到的起点或终点不在屏幕内
+    OffScreen { x: u16, y: u16 },
+}
+
+// 字符图形内容的校验错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TextError {
+    // 超过 30 字节
+    TooLong,
+    // 含有可打印 ASCII 与换行以外的字符
+    InvalidChar,
+    // 图形配置不是字符类型
+    NotText,
+    // 图形配置本身不合法（如起点在屏幕外）
+    Figure(FigureError),
+}
+
+// 0x0110 字符图形：图形配置 + 30 字节内容，长度写入 details_b
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TextFigure {
+    figure: Figure,
+    data: [u8; 30],
+}
+
+impl TextFigure {
+    pub const MAX_LEN: usize = 30;
+
+    pub fn new(
+        name: [u8; 3],
+        layer: u8,
+        color: FigureColor,
+        font_size: u16,
+        width: u16,
+        pos: ScreenPoint,
+        text: &str,
+    ) -> Result<Self, TextError> {
+        let mut fig = Self {
+            figure: Figure::text(name, layer, color, font_size, width, pos, 0),
+            data: [0; 30],
+        };
+        fig.set_text(text)?;
+        Ok(fig)
+    }
+
+    // 不会失败的构造：可打印 ASCII 与换行以外的字符替换为 '?'，超过 30 字节的部分截断
+    pub fn new_lossy(
+        name: [u8; 3],
+        layer: u8,
+        color: FigureColor,
+        font_size: u16,
+        width: u16,
+        pos: ScreenPoint,
+        text: &str,
+    ) -> Self {
+        let mut data = [0; 30];
+        let mut len = 0;
+        for (slot, c) in data.iter_mut().zip(text.chars()) {
+            *slot = u8::try_from(c)
+                .ok()
+                .filter(|b| is_text_byte(*b))
+                .unwrap_or(b'?');
+            len += 1;
+        }
+        Self {
+            figure: Figure::text(name, layer, color, font_size, width, pos, len),
+            data,
+        }
+    }
+
+    // 从收到的 0x0110 内容解码，校验长度与字符集
+    pub fn from_custom_character(
+        figure: &InteractionFigure,
+        data: &[u8; 30],
+    ) -> Result<Self, TextError> {
+        let figure = Figure::try_from(figure).map_err(TextError::Figure)?;
+        let FigureShape::Text { length, .. } = figure.shape else {
+            return Err(TextError::NotText);
+        };
+        let len = usize::from(length);
+        if len > Self::MAX_LEN {
+            return Err(TextError::TooLong);
+        }
+        if !data[..len].iter().copied().all(is_text_byte) {
+            return Err(TextError::InvalidChar);
+        }
+        // 长度之后的字节可能是发送方残留，解码后统一清零
+        let mut buf = [0; 30];
+        buf[..len].copy_from_slice(&data[..len]);
+        Ok(Self { figure, data: buf })
+    }
+
+    pub fn set_text(&mut self, text: &str) -> Result<(), TextError> {
+        let bytes = text.as_bytes();
+        if bytes.len() > Self::MAX_LEN {
+            return Err(TextError::TooLong);
+        }
+        if !bytes.iter().copied().all(is_text_byte) {
+            return Err(TextError::InvalidChar);
+        }
+        self.data = [0; 30];
+        self.data[..bytes.len()].copy_from_slice(bytes);
+        if let FigureShape::Text { length, .. } = &mut self.figure.shape {
+            *length = bytes.len() as u16;
+        }
+        Ok(())
+    }
+
+    pub fn text(&self) -> &str {
+        let len = match self.figure.shape {
+            FigureShape::Text { length, .. } => usize::from(length),
+            _ => 0,
+        };
+        // 构造与解码时已保证为 ASCII
+        core::str::from_utf8(&self.data[..len]).unwrap_or_default()
+    }
+
+    pub fn figure(&self) -> &Figure {
+        &self.figure
+    }
+
+    pub fn data(&self) -> &[u8; 30] {
+        &self.data
+    }
+
+    pub fn to_user_data(&self, operate_type: FigureOperateType) -> RobotInteractionUserData {
+        RobotInteractionUserData::CustomCharacter {
+            figure: self.figure.to_interaction(operate_type),
+            data: self.data,
+        }
+    }
+}
+
+fn is_text_byte(b: u8) -> bool {
+    b.is_ascii_graphic() || b == b' ' || b == b'\n'
+}
@@ -0,0 +1,164 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{FigureOperateType, InteractionFigure, RobotInteractionUserData};
+
+// 名称后两字节的字符表：每个图层 62 × 62 = 3844 个名称
+const ALPHABET: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+const SLOTS: usize = ALPHABET.len() * ALPHABET.len();
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NameError {
+    // 图层超出 0-9
+    LayerOutOfRange,
+    // 该图层名称已分配完
+    Exhausted,
+    // 外部图形使用了本分配器持有的名称
+    Collision([u8; 3]),
+}
+
+// 图形名称分配器：名称首字节为图层号 '0'-'9'，后两字节取自字母数字表，因此不同图层间天然不重名。
+// 可用可读标签申请名称，标签经 FNV-1a 哈希稳定映射到 3 字节名称，仅在哈希冲突时线性探测。
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FigureNameAllocator {
+    owned: BTreeMap<[u8; 3], Option<String>>,
+    labels: BTreeMap<String, [u8; 3]>,
+    foreign: BTreeSet<[u8; 3]>,
+    // 各图层顺序分配的游标，避免刚释放的名称被立即复用
+    cursors: [usize; 10],
+}
+
+impl FigureNameAllocator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 在指定图层分配一个未使用的名称
+    pub fn allocate(&mut self, layer: u8) -> Result<[u8; 3], NameError> {
+        let start = self.cursors.get(usize::from(layer)).copied().unwrap_or(0);
+        let name = self.probe(layer, start)?;
+        self.cursors[usize::from(layer)] = (slot_of(&name) + 1) % SLOTS;
+        self.owned.insert(name, None);
+        Ok(name)
+    }
+
+    // 按标签取得名称；同一标签重复调用返回相同名称
+    pub fn named(&mut self, layer: u8, label: &str) -> Result<[u8; 3], NameError> {
+        if let Some(name) = self.labels.get(label) {
+            if name[0] == layer_byte(layer)? {
+                return Ok(*name);
+            }
+            // 标签换了图层：释放旧名称后重新分配
+            let old = *name;
+            self.free(&old);
+        }
+        let name = self.probe(layer, fnv1a(label.as_bytes()) as usize % SLOTS)?;
+        self.owned.insert(name, Some(label.to_string()));
+        self.labels.insert(label.to_string(), name);
+        Ok(name)
+    }
+
+    // 图形删除后释放名称
+    pub fn free(&mut self, name: &[u8; 3]) -> bool {
+        match self.owned.remove(name) {
+            Some(label) => {
+                if let Some(label) = label {
+                    self.labels.remove(&label);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn is_owned(&self, name: &[u8; 3]) -> bool {
+        self.owned.contains_key(name)
+    }
+
+    pub fn label_of(&self, name: &[u8; 3]) -> Option<&str> {
+        self.owned.get(name)?.as_deref()
+    }
+
+    pub fn is_foreign(&self, name: &[u8; 3]) -> bool {
+        self.foreign.contains(name)
+    }
+
+    // 记录解码得到的外部图形；外部新增的名称不再分配，与自有名称冲突时报错
+    pub fn observe(&mut self, figure: &InteractionFigure) -> Result<(), NameError> {
+        let name = figure.figure_name;
+        if figure.operate_type == FigureOperateType::None {
+            return Ok(());
+        }
+        if self.is_owned(&name) {
+            return Err(NameError::Collision(name));
+        }
+        match figure.operate_type {
+            FigureOperateType::Delete => {
+                self.foreign.remove(&name);
+            }
+            _ => {
+                self.foreign.insert(name);
+            }
+        }
+        Ok(())
+    }
+
+    // 记录一条 0x0301 图形子内容，返回其中与自有名称冲突的名称
+    pub fn observe_user_data(&mut self, data: &RobotInteractionUserData) -> Vec<[u8; 3]> {
+        let figures: &[InteractionFigure] = match data {
+            RobotInteractionUserData::Figure(f) => core::slice::from_ref(f),
+            RobotInteractionUserData::Figures2(f) => f,
+            RobotInteractionUserData::Figures5(f) => f,
+            RobotInteractionUserData::Figures7(f) => f,
+            RobotInteractionUserData::CustomCharacter { figure, .. } => {
+                core::slice::from_ref(figure)
+            }
+            _ => &[],
+        };
+        figures
+            .iter()
+            .filter_map(|f| match self.observe(f) {
+                Err(NameError::Collision(name)) => Some(name),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn probe(&self, layer: u8, start: usize) -> Result<[u8; 3], NameError> {
+        let prefix = layer_byte(layer)?;
+        (0..SLOTS)
+            .map(|i| {
+                let slot = (start + i) % SLOTS;
+                [
+                    prefix,
+                    ALPHABET[slot / ALPHABET.len()],
+                    ALPHABET[slot % ALPHABET.len()],
+                ]
+            })
+            .find(|name| !self.owned.contains_key(name) && !self.foreign.contains(name))
+            .ok_or(NameError::Exhausted)
+    }
+}
+
+fn layer_byte(layer: u8) -> Result<u8, NameError> {
+    if layer <= 9 {
+        Ok(b'0' + layer)
+    } else {
+        Err(NameError::LayerOutOfRange)
+    }
+}
+
+fn slot_of(name: &[u8; 3]) -> usize {
+    let index = |b: u8| ALPHABET.iter().position(|&c| c == b).unwrap_or(0);
+    index(name[1]) * ALPHABET.len() + index(name[2])
+}
+
+fn fnv1a(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811C_9DC5, |hash, &b| {
+        (hash ^ u32::from(b)).wrapping_mul(0x0100_0193)
+    })
+}
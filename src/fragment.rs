@@ -0,0 +1,216 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::time::Duration;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::robot_interaction::MAX_INTERACTION_CONTENT_LEN;
+use crate::robot_message::check_id;
+use crate::{EndpointId, MessageError, RefereeFrameCmdData};
+
+// 分片传输默认使用的子内容 ID
+pub const FRAGMENT_DATA_CMD_ID: u16 = 0x02F0;
+// 分片头：消息序号、分片序号、分片总数各 1 字节
+pub const FRAGMENT_HEADER_LEN: usize = 3;
+pub const FRAGMENT_CHUNK_LEN: usize = MAX_INTERACTION_CONTENT_LEN - FRAGMENT_HEADER_LEN;
+// 单条消息最多 255 个分片
+pub const MAX_FRAGMENTED_LEN: usize = FRAGMENT_CHUNK_LEN * u8::MAX as usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FragmentError {
+    Empty,
+    TooLarge(usize),
+}
+
+// 将字节块拆分为编号的 0x0301 分片
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fragmenter {
+    data_cmd_id: u16,
+    next_message_id: u8,
+}
+
+impl Default for Fragmenter {
+    fn default() -> Self {
+        Self {
+            data_cmd_id: FRAGMENT_DATA_CMD_ID,
+            next_message_id: 0,
+        }
+    }
+}
+
+impl Fragmenter {
+    // data_cmd_id 须在 0x0200~0x02FF
+    pub fn new(data_cmd_id: u16) -> Result<Self, MessageError> {
+        check_id(data_cmd_id)?;
+        Ok(Self {
+            data_cmd_id,
+            next_message_id: 0,
+        })
+    }
+
+    pub fn split(
+        &mut self,
+        sender_id: EndpointId,
+        receiver_id: EndpointId,
+        blob: &[u8],
+    ) -> Result<Vec<RefereeFrameCmdData>, FragmentError> {
+        if blob.is_empty() {
+            return Err(FragmentError::Empty);
+        }
+        if blob.len() > MAX_FRAGMENTED_LEN {
+            return Err(FragmentError::TooLarge(blob.len()));
+        }
+        let message_id = self.next_message_id;
+        self.next_message_id = self.next_message_id.wrapping_add(1);
+        let count = blob.len().div_ceil(FRAGMENT_CHUNK_LEN) as u8;
+        Ok(blob
+            .chunks(FRAGMENT_CHUNK_LEN)
+            .enumerate()
+            .map(|(index, chunk)| {
+                let mut user_data = Vec::with_capacity(FRAGMENT_HEADER_LEN + chunk.len());
+                user_data.extend_from_slice(&[message_id, index as u8, count]);
+                user_data.extend_from_slice(chunk);
+                RefereeFrameCmdData::RobotInteractionData {
+                    data_cmd_id: self.data_cmd_id,
+                    sender_id,
+                    receiver_id,
+                    user_data,
+                }
+            })
+            .collect())
+    }
+}
+
+// 重组完成的消息
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Reassembled {
+    pub sender_id: EndpointId,
+    pub message_id: u8,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Partial {
+    sender_id: EndpointId,
+    chunks: Vec<Option<Vec<u8>>>,
+    received: usize,
+    last_seen: Duration,
+}
+
+// 接收端重组：按（发送方，消息序号）收集分片，乱序可接受；
+// 超过 timeout 未收到新分片的消息整体丢弃，同时进行的未完成消息数有上限。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reassembler {
+    data_cmd_id: u16,
+    timeout: Duration,
+    pending: BTreeMap<(u16, u8), Partial>,
+    dropped: u32,
+}
+
+impl Reassembler {
+    pub const MAX_PENDING: usize = 8;
+
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            data_cmd_id: FRAGMENT_DATA_CMD_ID,
+            timeout,
+            pending: BTreeMap::new(),
+            dropped: 0,
+        }
+    }
+
+    // data_cmd_id 须在 0x0200~0x02FF
+    pub fn with_data_cmd_id(mut self, data_cmd_id: u16) -> Result<Self, MessageError> {
+        check_id(data_cmd_id)?;
+        self.data_cmd_id = data_cmd_id;
+        Ok(self)
+    }
+
+    // 处理一条 0x0301 数据；非本 ID 的数据、格式错误的分片与重复分片被忽略
+    pub fn push(&mut self, cmd_data: &RefereeFrameCmdData, now: Duration) -> Option<Reassembled> {
+        self.expire(now);
+        let RefereeFrameCmdData::RobotInteractionData {
+            data_cmd_id,
+            sender_id,
+            user_data,
+            ..
+        } = cmd_data
+        else {
+            return None;
+        };
+        if *data_cmd_id != self.data_cmd_id {
+            return None;
+        }
+        let (&[message_id, index, count], chunk) = user_data.split_first_chunk()?;
+        if index >= count || chunk.is_empty() {
+            return None;
+        }
+        let key = (*sender_id as u16, message_id);
+        // 消息序号回绕后复用：分片总数不一致时视为新消息
+        if self
+            .pending
+            .get(&key)
+            .is_some_and(|p| p.chunks.len() != usize::from(count))
+        {
+            self.pending.remove(&key);
+            self.dropped += 1;
+        }
+        if !self.pending.contains_key(&key) && self.pending.len() >= Self::MAX_PENDING {
+            self.drop_oldest();
+        }
+        let partial = self.pending.entry(key).or_insert_with(|| Partial {
+            sender_id: *sender_id,
+            chunks: alloc::vec![None; usize::from(count)],
+            received: 0,
+            last_seen: now,
+        });
+        partial.last_seen = now;
+        let slot = &mut partial.chunks[usize::from(index)];
+        if slot.is_none() {
+            *slot = Some(chunk.to_vec());
+            partial.received += 1;
+        }
+        if partial.received < partial.chunks.len() {
+            return None;
+        }
+        let partial = self.pending.remove(&key)?;
+        Some(Reassembled {
+            sender_id: partial.sender_id,
+            message_id,
+            data: partial.chunks.into_iter().flatten().flatten().collect(),
+        })
+    }
+
+    // 丢弃超时的未完成消息，返回丢弃数量
+    pub fn expire(&mut self, now: Duration) -> usize {
+        let before = self.pending.len();
+        self.pending
+            .retain(|_, p| now.saturating_sub(p.last_seen) <= self.timeout);
+        let expired = before - self.pending.len();
+        self.dropped += expired as u32;
+        expired
+    }
+
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+
+    // 累计丢弃的不完整消息数
+    pub fn dropped(&self) -> u32 {
+        self.dropped
+    }
+
+    fn drop_oldest(&mut self) {
+        let oldest = self
+            .pending
+            .iter()
+            .min_by_key(|(_, p)| p.last_seen)
+            .map(|(key, _)| *key);
+        if let Some(key) = oldest {
+            self.pending.remove(&key);
+            self.dropped += 1;
+        }
+    }
+}
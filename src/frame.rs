@@ -1,5 +1,5 @@
 use crc::{Algorithm, Crc};
-use deku::{DekuContainerWrite, deku_derive};
+use deku::{DekuContainerWrite, DekuError, DekuUpdate, deku_derive};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -60,6 +60,20 @@ pub struct RefereeFrame {
 }
 
 impl RefereeFrame {
+    // 由命令数据构造完整帧，自动填充长度与 CRC
+    pub fn new(seq: u8, cmd_data: RefereeFrameCmdData) -> Result<Self, DekuError> {
+        let mut frame = RefereeFrame {
+            header: RefereeFrameHeader {
+                seq,
+                ..Default::default()
+            },
+            cmd_data,
+            frame_tail: 0,
+        };
+        frame.update()?;
+        Ok(frame)
+    }
+
     fn recompute_header_for_frame(&self) -> RefereeFrameHeader {
         let cmd_bytes = self.cmd_data.to_bytes().unwrap();
         let data_length = (cmd_bytes.len() as u16).saturating_sub(2);
@@ -0,0 +1,200 @@
+use alloc::collections::VecDeque;
+use core::time::Duration;
+
+use crate::{RefereeFrameCmdData, ShooterNumber};
+
+// 每发弹丸增加的枪口热量
+pub const HEAT_PER_17MM: u16 = 10;
+pub const HEAT_PER_42MM: u16 = 100;
+// 裁判系统按 10Hz 结算冷却，每次冷却 cooling_value / 10
+pub const HEAT_TICK: Duration = Duration::from_millis(100);
+// 本地记录的发射等待 0x0207 确认的时长，超时后不再与 0x0207 配对
+const CONFIRM_WINDOW: Duration = Duration::from_millis(500);
+
+// 单个发射机构的枪口热量模型：在 10Hz 的 0x0202 之间用本地发射计数与冷却估计热量，
+// 预测当前可发射的弹丸数，并在每条 0x0202 到达时重新同步。
+// 估计偏保守：冷却只按已满的结算周期计算；本地发射在上报热量的增长确认其已计入之前一直计入估计，
+// 超过 latency 仍未被上报确认的发射（如未实际射出）才被丢弃。
+// 收到 0x0201 与 0x0202 之前不允许发射。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeatModel {
+    shooter: ShooterNumber,
+    latency: Duration,
+    cooling: Option<u16>,
+    limit: Option<u16>,
+    // 热量估计，单位 0.1
+    heat_tenths: Option<u32>,
+    last_tick: Duration,
+    // 上一条 0x0202 的热量
+    last_reported: Option<u16>,
+    // 尚未被上报热量确认的发射时刻
+    recent: VecDeque<Duration>,
+    // 本地记录、尚未被 0x0207 确认的发射时刻
+    unconfirmed: VecDeque<Duration>,
+}
+
+impl HeatModel {
+    // 3 个 0x0202 上报周期
+    pub const DEFAULT_LATENCY: Duration = Duration::from_millis(300);
+
+    pub fn new(shooter: ShooterNumber) -> Self {
+        Self {
+            shooter,
+            latency: Self::DEFAULT_LATENCY,
+            cooling: None,
+            limit: None,
+            heat_tenths: None,
+            last_tick: Duration::ZERO,
+            last_reported: None,
+            recent: VecDeque::new(),
+            unconfirmed: VecDeque::new(),
+        }
+    }
+
+    // 裁判系统上报热量相对发射的最大滞后：超过该时长仍未被上报确认的发射不再计入
+    pub fn with_latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    pub fn shooter(&self) -> ShooterNumber {
+        self.shooter
+    }
+
+    pub fn heat_per_shot(&self) -> u16 {
+        match self.shooter {
+            ShooterNumber::Shooter17mm1 | ShooterNumber::Shooter17mm2 => HEAT_PER_17MM,
+            ShooterNumber::Shooter42mm => HEAT_PER_42MM,
+        }
+    }
+
+    pub fn cooling(&self) -> Option<u16> {
+        self.cooling
+    }
+
+    pub fn limit(&self) -> Option<u16> {
+        self.limit
+    }
+
+    // 喂入裁判系统数据：0x0201 更新冷却与上限，0x0202 重新同步，0x0207 计入未在本地记录的发射
+    pub fn update(&mut self, data: &RefereeFrameCmdData, now: Duration) -> bool {
+        match data {
+            RefereeFrameCmdData::RobotStatus {
+                shooter_barrel_cooling_value,
+                shooter_barrel_heat_limit,
+                ..
+            } => {
+                self.advance(now);
+                let changed = self.cooling != Some(*shooter_barrel_cooling_value)
+                    || self.limit != Some(*shooter_barrel_heat_limit);
+                self.cooling = Some(*shooter_barrel_cooling_value);
+                self.limit = Some(*shooter_barrel_heat_limit);
+                changed
+            }
+            RefereeFrameCmdData::PowerHeatData {
+                shooter_17mm_1_barrel_heat,
+                shooter_17mm_2_barrel_heat,
+                shooter_42mm_barrel_heat,
+                ..
+            } => {
+                let reported = match self.shooter {
+                    ShooterNumber::Shooter17mm1 => *shooter_17mm_1_barrel_heat,
+                    ShooterNumber::Shooter17mm2 => *shooter_17mm_2_barrel_heat,
+                    ShooterNumber::Shooter42mm => *shooter_42mm_barrel_heat,
+                };
+                // 热量只因发射增长：相对上一条上报的增量至少对应这么多发发射已被计入
+                if let Some(last) = self.last_reported {
+                    let counted = reported.saturating_sub(last).div_ceil(self.heat_per_shot());
+                    let counted = usize::from(counted).min(self.recent.len());
+                    self.recent.drain(..counted);
+                }
+                self.last_reported = Some(reported);
+                self.prune(now);
+                let pending = self.recent.len() as u32 * u32::from(self.heat_per_shot());
+                self.heat_tenths = Some((u32::from(reported) + pending) * 10);
+                self.last_tick = now;
+                true
+            }
+            RefereeFrameCmdData::ShootData { shooter_number, .. }
+                if *shooter_number == self.shooter =>
+            {
+                self.prune(now);
+                if self.unconfirmed.pop_front().is_some() {
+                    return false;
+                }
+                self.add_shot(now);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // 本机发射了一发弹丸（早于对应的 0x0207 到达）
+    pub fn record_shot(&mut self, now: Duration) {
+        self.prune(now);
+        self.unconfirmed.push_back(now);
+        self.add_shot(now);
+    }
+
+    // 当前热量估计（向上取整）；尚未同步时为 None
+    pub fn heat(&self, now: Duration) -> Option<u16> {
+        let tenths = self.heat_tenths_at(now)?;
+        Some(tenths.div_ceil(10).min(u32::from(u16::MAX)) as u16)
+    }
+
+    // 当前可连续发射且热量不超过上限的弹丸数
+    pub fn shots_allowed(&self, now: Duration) -> u16 {
+        let (Some(limit), Some(heat)) = (self.limit, self.heat_tenths_at(now)) else {
+            return 0;
+        };
+        let headroom = (u32::from(limit) * 10).saturating_sub(heat);
+        let shots = headroom / (u32::from(self.heat_per_shot()) * 10);
+        shots.min(u32::from(u16::MAX)) as u16
+    }
+
+    pub fn can_shoot(&self, now: Duration) -> bool {
+        self.shots_allowed(now) > 0
+    }
+
+    fn heat_tenths_at(&self, now: Duration) -> Option<u32> {
+        let heat = self.heat_tenths?;
+        self.cooling?;
+        Some(heat.saturating_sub(self.cooled_tenths(now)))
+    }
+
+    // 自 last_tick 起已满的结算周期数
+    fn elapsed_ticks(&self, now: Duration) -> u32 {
+        let ticks = now.saturating_sub(self.last_tick).as_millis() / HEAT_TICK.as_millis();
+        u32::try_from(ticks).unwrap_or(u32::MAX)
+    }
+
+    // 自 last_tick 起已结算的冷却量（单位 0.1）
+    fn cooled_tenths(&self, now: Duration) -> u32 {
+        self.elapsed_ticks(now)
+            .saturating_mul(u32::from(self.cooling.unwrap_or(0)))
+    }
+
+    fn advance(&mut self, now: Duration) {
+        let cooled = self.cooled_tenths(now);
+        if let Some(heat) = &mut self.heat_tenths {
+            *heat = heat.saturating_sub(cooled);
+        }
+        self.last_tick += HEAT_TICK * self.elapsed_ticks(now);
+    }
+
+    fn add_shot(&mut self, now: Duration) {
+        self.advance(now);
+        self.recent.push_back(now);
+        let per_shot = u32::from(self.heat_per_shot()) * 10;
+        if let Some(heat) = &mut self.heat_tenths {
+            *heat += per_shot;
+        }
+    }
+
+    fn prune(&mut self, now: Duration) {
+        let latency = self.latency;
+        self.recent.retain(|t| now.saturating_sub(*t) <= latency);
+        self.unconfirmed
+            .retain(|t| now.saturating_sub(*t) <= CONFIRM_WINDOW);
+    }
+}
@@ -0,0 +1,235 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Figure, FigureOperateType, InteractionFigure, RobotInteractionUserData, TextFigure};
+
+// 场景中的一个元素：普通图形（可批量打包）或字符图形（单独 0x0110）
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HudElement {
+    Figure(Figure),
+    Text(TextFigure),
+}
+
+impl HudElement {
+    pub fn name(&self) -> [u8; 3] {
+        match self {
+            HudElement::Figure(f) => f.name,
+            HudElement::Text(t) => t.figure().name,
+        }
+    }
+
+    pub fn layer(&self) -> u8 {
+        match self {
+            HudElement::Figure(f) => f.layer,
+            HudElement::Text(t) => t.figure().layer,
+        }
+    }
+
+    pub fn figure(&self) -> &Figure {
+        match self {
+            HudElement::Figure(f) => f,
+            HudElement::Text(t) => t.figure(),
+        }
+    }
+}
+
+impl From<Figure> for HudElement {
+    fn from(f: Figure) -> Self {
+        HudElement::Figure(f)
+    }
+}
+
+impl From<TextFigure> for HudElement {
+    fn from(t: TextFigure) -> Self {
+        HudElement::Text(t)
+    }
+}
+
+// 一次图形操作
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HudOp {
+    pub operate_type: FigureOperateType,
+    pub element: HudElement,
+}
+
+impl HudOp {
+    // 普通图形操作与删除可批量打包；字符图形的新增 / 修改需单独的 0x0110
+    pub fn is_batchable(&self) -> bool {
+        self.operate_type == FigureOperateType::Delete
+            || matches!(self.element, HudElement::Figure(_))
+    }
+
+    pub fn to_interaction(&self) -> InteractionFigure {
+        self.element.figure().to_interaction(self.operate_type)
+    }
+
+    pub fn to_user_data(&self) -> RobotInteractionUserData {
+        match (self.is_batchable(), &self.element) {
+            (false, HudElement::Text(t)) => t.to_user_data(self.operate_type),
+            _ => RobotInteractionUserData::Figure(self.to_interaction()),
+        }
+    }
+}
+
+// 保留模式的选手端 UI 场景：维护期望的图形集合，与上次发送的状态比较，只生成必要的新增 / 修改 / 删除
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HudScene {
+    desired: BTreeMap<[u8; 3], HudElement>,
+    sent: BTreeMap<[u8; 3], HudElement>,
+}
+
+impl HudScene {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 新增或替换同名元素
+    pub fn set(&mut self, element: impl Into<HudElement>) {
+        let element = element.into();
+        self.desired.insert(element.name(), element);
+    }
+
+    pub fn remove(&mut self, name: &[u8; 3]) -> Option<HudElement> {
+        self.desired.remove(name)
+    }
+
+    pub fn clear(&mut self) {
+        self.desired.clear();
+    }
+
+    pub fn get(&self, name: &[u8; 3]) -> Option<&HudElement> {
+        self.desired.get(name)
+    }
+
+    pub fn elements(&self) -> impl Iterator<Item = &HudElement> {
+        self.desired.values()
+    }
+
+    // 客户端状态未知（如选手端重启）时调用，下次更新会重新新增全部元素
+    pub fn invalidate(&mut self) {
+        self.sent.clear();
+    }
+
+    // 与上次发送状态的差异：先删除，再新增，最后修改
+    pub fn diff(&self) -> Vec<HudOp> {
+        let deletes = self
+            .sent
+            .iter()
+            .filter(|(name, _)| !self.desired.contains_key(*name))
+            .map(|(_, element)| HudOp {
+                operate_type: FigureOperateType::Delete,
+                element: *element,
+            });
+        let adds = self
+            .desired
+            .iter()
+            .filter(|(name, _)| !self.sent.contains_key(*name))
+            .map(|(_, element)| HudOp {
+                operate_type: FigureOperateType::Add,
+                element: *element,
+            });
+        let modifies = self
+            .desired
+            .iter()
+            .filter(|(name, element)| {
+                self.sent
+                    .get(*name)
+                    .is_some_and(|old| !same_on_wire(old, element))
+            })
+            .map(|(_, element)| HudOp {
+                operate_type: FigureOperateType::Modify,
+                element: *element,
+            });
+        deletes.chain(adds).chain(modifies).collect()
+    }
+
+    // 记录一次操作已发送
+    pub fn commit(&mut self, op: &HudOp) {
+        let name = op.element.name();
+        match op.operate_type {
+            FigureOperateType::Delete => {
+                self.sent.remove(&name);
+            }
+            FigureOperateType::Add | FigureOperateType::Modify => {
+                self.sent.insert(name, op.element);
+            }
+            FigureOperateType::None => {}
+        }
+    }
+
+    // 计算差异、打包为最少的 0x0301 子内容，并视为已发送
+    pub fn update(&mut self) -> Vec<RobotInteractionUserData> {
+        let ops = self.diff();
+        for op in &ops {
+            self.commit(op);
+        }
+        pack_ops(&ops)
+    }
+}
+
+fn same_on_wire(a: &HudElement, b: &HudElement) -> bool {
+    match (a, b) {
+        (HudElement::Figure(x), HudElement::Figure(y)) => {
+            x.to_interaction(FigureOperateType::Add) == y.to_interaction(FigureOperateType::Add)
+        }
+        (HudElement::Text(x), HudElement::Text(y)) => {
+            x.figure().to_interaction(FigureOperateType::Add)
+                == y.figure().to_interaction(FigureOperateType::Add)
+                && x.data() == y.data()
+        }
+        _ => false,
+    }
+}
+
+// 将操作打包为数量最少的子内容：可批量的图形按 7 / 5 / 2 / 1 精确组合（不填充空操作），字符图形各占一条 0x0110
+pub fn pack_ops(ops: &[HudOp]) -> Vec<RobotInteractionUserData> {
+    let batch: Vec<InteractionFigure> = ops
+        .iter()
+        .filter(|op| op.is_batchable())
+        .map(HudOp::to_interaction)
+        .collect();
+    let mut out = Vec::new();
+    let mut rest = batch.as_slice();
+    for size in batch_sizes(batch.len()) {
+        let (chunk, tail) = rest.split_at(size);
+        rest = tail;
+        out.push(match size {
+            7 => RobotInteractionUserData::Figures7(chunk.try_into().unwrap()),
+            5 => RobotInteractionUserData::Figures5(chunk.try_into().unwrap()),
+            2 => RobotInteractionUserData::Figures2(chunk.try_into().unwrap()),
+            _ => RobotInteractionUserData::Figure(chunk[0]),
+        });
+    }
+    out.extend(
+        ops.iter()
+            .filter(|op| !op.is_batchable())
+            .map(HudOp::to_user_data),
+    );
+    out
+}
+
+// n 个图形拆分为 7 / 5 / 2 / 1 的最少条数组合（从大到小）
+fn batch_sizes(n: usize) -> Vec<usize> {
+    const SIZES: [usize; 4] = [7, 5, 2, 1];
+    let mut best = alloc::vec![(0usize, 0usize); n + 1];
+    for i in 1..=n {
+        best[i] = SIZES
+            .iter()
+            .filter(|&&s| s <= i)
+            .map(|&s| (best[i - s].0 + 1, s))
+            .min_by_key(|&(count, _)| count)
+            .unwrap();
+    }
+    let mut sizes = Vec::new();
+    let mut i = n;
+    while i > 0 {
+        sizes.push(best[i].1);
+        i -= best[i].1;
+    }
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    sizes
+}
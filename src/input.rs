@@ -0,0 +1,345 @@
+use core::time::Duration;
+
+use bitflags::bitflags;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::RefereeFrameCmdData;
+
+bitflags! {
+    // 0x0304 / 0x0306 键盘键值：bit0-15 依次为 W S A D Shift Ctrl Q E R F G Z X C V B
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct Keys: u16 {
+        const W = 1 << 0;
+        const S = 1 << 1;
+        const A = 1 << 2;
+        const D = 1 << 3;
+        const SHIFT = 1 << 4;
+        const CTRL = 1 << 5;
+        const Q = 1 << 6;
+        const E = 1 << 7;
+        const R = 1 << 8;
+        const F = 1 << 9;
+        const G = 1 << 10;
+        const Z = 1 << 11;
+        const X = 1 << 12;
+        const C = 1 << 13;
+        const V = 1 << 14;
+        const B = 1 << 15;
+    }
+}
+
+bitflags! {
+    // 鼠标按键状态
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct MouseButtons: u8 {
+        const LEFT = 1 << 0;
+        const RIGHT = 1 << 1;
+    }
+}
+
+// 单键与通用键值的对应关系（0x0303 cmd_keyboard）：字母键为大写 ASCII，Shift 0x10，Ctrl 0x11
+const KEY_CODES: [(Keys, u8); 16] = [
+    (Keys::W, b'W'),
+    (Keys::S, b'S'),
+    (Keys::A, b'A'),
+    (Keys::D, b'D'),
+    (Keys::SHIFT, 0x10),
+    (Keys::CTRL, 0x11),
+    (Keys::Q, b'Q'),
+    (Keys::E, b'E'),
+    (Keys::R, b'R'),
+    (Keys::F, b'F'),
+    (Keys::G, b'G'),
+    (Keys::Z, b'Z'),
+    (Keys::X, b'X'),
+    (Keys::C, b'C'),
+    (Keys::V, b'V'),
+    (Keys::B, b'B'),
+];
+
+impl Keys {
+    // 通用键值 → 单键；0 或未知键值返回 None
+    pub fn from_key_code(code: u8) -> Option<Keys> {
+        KEY_CODES
+            .iter()
+            .find(|(_, c)| *c == code.to_ascii_uppercase())
+            .map(|(k, _)| *k)
+    }
+
+    // 单键 → 通用键值；空集或多键组合返回 None
+    pub fn key_code(self) -> Option<u8> {
+        KEY_CODES.iter().find(|(k, _)| *k == self).map(|(_, c)| *c)
+    }
+}
+
+impl MouseButtons {
+    pub fn new(left: bool, right: bool) -> Self {
+        let mut buttons = MouseButtons::empty();
+        buttons.set(MouseButtons::LEFT, left);
+        buttons.set(MouseButtons::RIGHT, right);
+        buttons
+    }
+
+    pub fn left(self) -> bool {
+        self.contains(MouseButtons::LEFT)
+    }
+
+    pub fn right(self) -> bool {
+        self.contains(MouseButtons::RIGHT)
+    }
+}
+
+impl RefereeFrameCmdData {
+    // 0x0304 / 0x0306 的键盘状态
+    pub fn keys(&self) -> Option<Keys> {
+        match *self {
+            RefereeFrameCmdData::RemoteControl { keyboard_value, .. }
+            | RefereeFrameCmdData::SimulateControl { keyboard_value, .. } => {
+                Some(Keys::from_bits_retain(keyboard_value))
+            }
+            _ => None,
+        }
+    }
+
+    // 写入 0x0304 / 0x0306 的键盘状态；其他命令返回 false
+    pub fn set_keys(&mut self, keys: Keys) -> bool {
+        match self {
+            RefereeFrameCmdData::RemoteControl { keyboard_value, .. }
+            | RefereeFrameCmdData::SimulateControl { keyboard_value, .. } => {
+                *keyboard_value = keys.bits();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // 0x0304 / 0x0306 的鼠标按键状态（非 0 即按下）
+    pub fn mouse_buttons(&self) -> Option<MouseButtons> {
+        match *self {
+            RefereeFrameCmdData::RemoteControl {
+                left_button_down,
+                right_button_down,
+                ..
+            } => Some(MouseButtons::new(
+                left_button_down != 0,
+                right_button_down != 0,
+            )),
+            RefereeFrameCmdData::SimulateControl {
+                mouse_left_state,
+                mouse_right_state,
+                ..
+            } => Some(MouseButtons::new(
+                mouse_left_state != 0,
+                mouse_right_state != 0,
+            )),
+            _ => None,
+        }
+    }
+
+    // 写入 0x0304 / 0x0306 的鼠标按键状态；其他命令返回 false
+    pub fn set_mouse_buttons(&mut self, buttons: MouseButtons) -> bool {
+        match self {
+            RefereeFrameCmdData::RemoteControl {
+                left_button_down,
+                right_button_down,
+                ..
+            } => {
+                *left_button_down = buttons.left().into();
+                *right_button_down = buttons.right().into();
+                true
+            }
+            RefereeFrameCmdData::SimulateControl {
+                mouse_left_state,
+                mouse_right_state,
+                ..
+            } => {
+                *mouse_left_state = buttons.left().into();
+                *mouse_right_state = buttons.right().into();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // 0x0303 云台手按下的按键；无按键或非上述 16 键时返回 None
+    pub fn map_command_key(&self) -> Option<Keys> {
+        match *self {
+            RefereeFrameCmdData::MapCommand { cmd_keyboard, .. } => {
+                Keys::from_key_code(cmd_keyboard)
+            }
+            _ => None,
+        }
+    }
+
+    // 写入 0x0303 云台手按键；None 表示无按键，多键组合无法表示，返回 false
+    pub fn set_map_command_key(&mut self, key: Option<Keys>) -> bool {
+        let code = match key {
+            None => 0,
+            Some(k) => match k.key_code() {
+                Some(c) => c,
+                None => return false,
+            },
+        };
+        match self {
+            RefereeFrameCmdData::MapCommand { cmd_keyboard, .. } => {
+                *cmd_keyboard = code;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+// 相邻两次更新之间的按键 / 鼠标按键变化
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct InputEdges {
+    pub pressed: Keys,
+    pub released: Keys,
+    pub buttons_pressed: MouseButtons,
+    pub buttons_released: MouseButtons,
+}
+
+impl InputEdges {
+    pub fn is_empty(&self) -> bool {
+        self.pressed.is_empty()
+            && self.released.is_empty()
+            && self.buttons_pressed.is_empty()
+            && self.buttons_released.is_empty()
+    }
+}
+
+// 基于 0x0304 键鼠遥控数据的输入跟踪器：跨帧保存键鼠状态，给出按下 / 松开沿、按住时长与累计鼠标位移。
+// 每帧都携带完整按键状态，丢帧时边沿会合并到下一帧；超过 timeout 未收到数据视为图传链路断开，释放全部输入。
+// 时间戳 now 由调用方提供的单调时钟给出。
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputTracker {
+    keys: Keys,
+    buttons: MouseButtons,
+    key_since: [Option<Duration>; 16],
+    button_since: [Option<Duration>; 2],
+    mouse_delta: (f32, f32, f32),
+    sensitivity: (f32, f32, f32),
+    timeout: Duration,
+    last_frame: Option<Duration>,
+}
+
+impl InputTracker {
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            keys: Keys::empty(),
+            buttons: MouseButtons::empty(),
+            key_since: [None; 16],
+            button_since: [None; 2],
+            mouse_delta: (0.0, 0.0, 0.0),
+            sensitivity: (1.0, 1.0, 1.0),
+            timeout,
+            last_frame: None,
+        }
+    }
+
+    // 设置鼠标 x / y / z 位移的缩放系数
+    pub fn with_sensitivity(mut self, x: f32, y: f32, z: f32) -> Self {
+        self.sensitivity = (x, y, z);
+        self
+    }
+
+    // 喂入一条命令数据；仅处理 0x0304，返回本帧产生的边沿
+    pub fn update(&mut self, data: &RefereeFrameCmdData, now: Duration) -> Option<InputEdges> {
+        let RefereeFrameCmdData::RemoteControl {
+            mouse_x,
+            mouse_y,
+            mouse_z,
+            ..
+        } = *data
+        else {
+            return None;
+        };
+        let edges = self.apply(data.keys()?, data.mouse_buttons()?, now);
+        self.mouse_delta.0 += f32::from(mouse_x) * self.sensitivity.0;
+        self.mouse_delta.1 += f32::from(mouse_y) * self.sensitivity.1;
+        self.mouse_delta.2 += f32::from(mouse_z) * self.sensitivity.2;
+        self.last_frame = Some(now);
+        Some(edges)
+    }
+
+    // 检查链路超时；超时后释放全部输入并返回对应的松开沿
+    pub fn poll(&mut self, now: Duration) -> InputEdges {
+        if self.is_linked(now) {
+            return InputEdges::default();
+        }
+        self.last_frame = None;
+        self.apply(Keys::empty(), MouseButtons::empty(), now)
+    }
+
+    pub fn is_linked(&self, now: Duration) -> bool {
+        self.last_frame
+            .is_some_and(|t| now.saturating_sub(t) <= self.timeout)
+    }
+
+    pub fn keys(&self) -> Keys {
+        self.keys
+    }
+
+    pub fn mouse_buttons(&self) -> MouseButtons {
+        self.buttons
+    }
+
+    pub fn is_pressed(&self, keys: Keys) -> bool {
+        !keys.is_empty() && self.keys.contains(keys)
+    }
+
+    // 单键已按住的时长；未按下或传入多键组合时返回 None
+    pub fn held_for(&self, key: Keys, now: Duration) -> Option<Duration> {
+        if key.bits().count_ones() != 1 {
+            return None;
+        }
+        let since = self.key_since[key.bits().trailing_zeros() as usize]?;
+        Some(now.saturating_sub(since))
+    }
+
+    // 单个鼠标按键已按住的时长
+    pub fn button_held_for(&self, button: MouseButtons, now: Duration) -> Option<Duration> {
+        if button.bits().count_ones() != 1 {
+            return None;
+        }
+        let since = self.button_since[button.bits().trailing_zeros() as usize]?;
+        Some(now.saturating_sub(since))
+    }
+
+    // 自上次取出以来累计的（已缩放）鼠标位移
+    pub fn mouse_delta(&self) -> (f32, f32, f32) {
+        self.mouse_delta
+    }
+
+    // 取出累计鼠标位移并清零
+    pub fn take_mouse_delta(&mut self) -> (f32, f32, f32) {
+        core::mem::replace(&mut self.mouse_delta, (0.0, 0.0, 0.0))
+    }
+
+    fn apply(&mut self, keys: Keys, buttons: MouseButtons, now: Duration) -> InputEdges {
+        let edges = InputEdges {
+            pressed: keys - self.keys,
+            released: self.keys - keys,
+            buttons_pressed: buttons - self.buttons,
+            buttons_released: self.buttons - buttons,
+        };
+        for key in edges.pressed.iter() {
+            self.key_since[key.bits().trailing_zeros() as usize] = Some(now);
+        }
+        for key in edges.released.iter() {
+            self.key_since[key.bits().trailing_zeros() as usize] = None;
+        }
+        for button in edges.buttons_pressed.iter() {
+            self.button_since[button.bits().trailing_zeros() as usize] = Some(now);
+        }
+        for button in edges.buttons_released.iter() {
+            self.button_since[button.bits().trailing_zeros() as usize] = None;
+        }
+        self.keys = keys;
+        self.buttons = buttons;
+        edges
+    }
+}
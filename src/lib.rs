@@ -1,6 +1,8 @@
 #![no_std]
 
 extern crate alloc;
+#[cfg(feature = "render")]
+extern crate std;
 use deku::deku_derive;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -8,9 +10,97 @@ use serde::{Deserialize, Serialize};
 pub mod robot_interaction;
 pub use crate::robot_interaction::{
     FigureColor, FigureOperateType, FigureType, InteractionFigure, InteractionLayerDelete,
-    LayerDeleteType, RadarCmd, RobotInteractionUserData, SentryCmd,
+    LayerDeleteType, MAX_INTERACTION_CONTENT_LEN, MAX_INTERACTION_RATE_HZ, RadarCmd,
+    RobotInteractionUserData, SentryCmd,
 };
 
+pub mod robot_message;
+pub use crate::robot_message::{
+    MessageError, MessageRegistry, ROBOT_MESSAGE_IDS, RobotMessage, WireSize,
+};
+#[cfg(feature = "derive")]
+pub use rm_referee_protocol_derive::RobotMessage;
+
+// 派生宏生成代码所用的路径，非公开 API
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::vec::Vec;
+    pub use deku;
+    #[cfg(feature = "serde")]
+    pub use serde;
+}
+
+pub mod fragment;
+pub use crate::fragment::{
+    FRAGMENT_CHUNK_LEN, FRAGMENT_DATA_CMD_ID, FragmentError, Fragmenter, Reassembled, Reassembler,
+};
+
+pub mod reliable;
+pub use crate::reliable::{
+    DeliveryFailure, RELIABLE_DATA_CMD_ID, RELIABLE_PAYLOAD_LEN, ReliableLink,
+};
+
+pub mod blackboard;
+pub use crate::blackboard::{
+    BLACKBOARD_DATA_CMD_ID, BLACKBOARD_MAX_VALUE_LEN, Blackboard, BlackboardError,
+};
+
+pub mod heat;
+pub use crate::heat::{HEAT_PER_17MM, HEAT_PER_42MM, HEAT_TICK, HeatModel};
+
+pub mod dart;
+pub use crate::dart::{DartStation, DartStationState};
+
+pub mod input;
+pub use crate::input::{InputEdges, InputTracker, Keys, MouseButtons};
+
+pub mod sentry;
+pub use crate::sentry::{SentryCommander, SentryStatus};
+
+pub mod radar;
+pub use crate::radar::{
+    MAP_ROBOT_DATA_RATE_HZ, MinimapPublisher, RadarCommander, RadarTriggerOutcome,
+};
+
+pub mod custom_info;
+pub use crate::custom_info::{CUSTOM_INFO_LEN, CustomInfo, CustomInfoError};
+
+pub mod field;
+pub use crate::field::{FieldError, FieldPoint, field_size};
+
+pub mod map_path;
+pub use crate::map_path::{MAP_PATH_DELTAS, MapPath, MapPathError, PathIntention};
+
+pub mod screen;
+pub use crate::screen::{
+    BoundsPolicy, SCREEN_HEIGHT, SCREEN_WIDTH, ScreenError, ScreenPoint, ScreenRect,
+};
+
+pub mod figure;
+pub use crate::figure::{Figure, FigureError, FigureShape, TextError, TextFigure, float_to_milli};
+
+pub mod figure_name;
+pub use crate::figure_name::{FigureNameAllocator, NameError};
+
+pub mod hud;
+pub use crate::hud::{HudElement, HudOp, HudScene};
+
+pub mod ui_scheduler;
+pub use crate::ui_scheduler::{UiPriority, UiScheduler};
+
+pub mod widget;
+pub use crate::widget::{
+    AmmoCounter, BuffIndicator, BufferEnergyBar, Crosshair, HeatBar, StageTimer, Widget,
+};
+
+pub mod virtual_client;
+pub use crate::virtual_client::{ClientViolation, VirtualClient};
+
+#[cfg(feature = "render")]
+pub mod render;
+#[cfg(feature = "render")]
+pub use crate::render::{Canvas, HudRenderer};
+
 mod frame;
 pub use frame::{FRAME_SOF, RM_CRC8, RM_CRC16, RefereeFrame, RefereeFrameHeader};
 mod types;
@@ -232,7 +322,7 @@ pub enum RefereeFrameCmdData {
     },
     #[deku(id = 0x020A)]
     DartClientCmd {
-        dart_launch_opening_status: u8,
+        dart_launch_opening_status: DartLaunchGate,
         #[deku(temp, temp_value = "u8::default()")]
         reserved: u8,
         target_change_time: u16,
@@ -377,10 +467,10 @@ impl RefereeFrameCmdData {
             user_data,
             ..
         } = self
+            && *data_cmd_id == 0x0120
+            && user_data.len() == 4
         {
-            if *data_cmd_id == 0x0120 && user_data.len() == 4 {
-                return SentryCmd::try_from(user_data.as_slice()).ok();
-            }
+            return SentryCmd::try_from(user_data.as_slice()).ok();
         }
         None
     }
@@ -392,10 +482,10 @@ impl RefereeFrameCmdData {
             user_data,
             ..
         } = self
+            && *data_cmd_id == 0x0121
+            && user_data.len() == 1
         {
-            if *data_cmd_id == 0x0121 && user_data.len() == 1 {
-                return RadarCmd::try_from(user_data.as_slice()).ok();
-            }
+            return RadarCmd::try_from(user_data.as_slice()).ok();
         }
         None
     }
@@ -482,10 +572,9 @@ impl RefereeFrameCmdData {
             user_data,
             ..
         } = self
+            && (0x0200..=0x02FF).contains(data_cmd_id)
         {
-            if (0x0200..=0x02FF).contains(data_cmd_id) {
-                return Some(user_data.as_slice());
-            }
+            return Some(user_data.as_slice());
         }
         None
     }
@@ -551,6 +640,51 @@ pub enum RobotID {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[deku(id_type = "u16", endian = "little")]
 pub enum EndpointId {
+    // Robots
+    #[deku(id = "0x0001")]
+    RedHero = 0x0001,
+    #[deku(id = "0x0002")]
+    RedEngineer = 0x0002,
+    #[deku(id = "0x0003")]
+    RedInfantry3 = 0x0003,
+    #[deku(id = "0x0004")]
+    RedInfantry4 = 0x0004,
+    #[deku(id = "0x0005")]
+    RedInfantry5 = 0x0005,
+    #[deku(id = "0x0006")]
+    RedAerial = 0x0006,
+    #[deku(id = "0x0007")]
+    RedSentry = 0x0007,
+    #[deku(id = "0x0008")]
+    RedDart = 0x0008,
+    #[deku(id = "0x0009")]
+    RedRadar = 0x0009,
+    #[deku(id = "0x000A")]
+    RedOutpost = 0x000A,
+    #[deku(id = "0x000B")]
+    RedBase = 0x000B,
+    #[deku(id = "0x0065")]
+    BlueHero = 0x0065,
+    #[deku(id = "0x0066")]
+    BlueEngineer = 0x0066,
+    #[deku(id = "0x0067")]
+    BlueInfantry3 = 0x0067,
+    #[deku(id = "0x0068")]
+    BlueInfantry4 = 0x0068,
+    #[deku(id = "0x0069")]
+    BlueInfantry5 = 0x0069,
+    #[deku(id = "0x006A")]
+    BlueAerial = 0x006A,
+    #[deku(id = "0x006B")]
+    BlueSentry = 0x006B,
+    #[deku(id = "0x006C")]
+    BlueDart = 0x006C,
+    #[deku(id = "0x006D")]
+    BlueRadar = 0x006D,
+    #[deku(id = "0x006E")]
+    BlueOutpost = 0x006E,
+    #[deku(id = "0x006F")]
+    BlueBase = 0x006F,
     // Player Clients
     #[deku(id = "0x0101")]
     RedHeroClient = 0x0101,
@@ -580,6 +714,72 @@ pub enum EndpointId {
     #[deku(id = "0x8080")]
     Server = 0x8080,
 }
+
+impl RobotID {
+    pub fn is_red(self) -> bool {
+        (self as u8) < 100
+    }
+}
+
+impl From<RobotID> for EndpointId {
+    fn from(id: RobotID) -> Self {
+        match id {
+            RobotID::RedHero => EndpointId::RedHero,
+            RobotID::RedEngineer => EndpointId::RedEngineer,
+            RobotID::RedInfantry3 => EndpointId::RedInfantry3,
+            RobotID::RedInfantry4 => EndpointId::RedInfantry4,
+            RobotID::RedInfantry5 => EndpointId::RedInfantry5,
+            RobotID::RedAerial => EndpointId::RedAerial,
+            RobotID::RedSentry => EndpointId::RedSentry,
+            RobotID::RedDart => EndpointId::RedDart,
+            RobotID::RedRadar => EndpointId::RedRadar,
+            RobotID::RedOutpost => EndpointId::RedOutpost,
+            RobotID::RedBase => EndpointId::RedBase,
+            RobotID::BlueHero => EndpointId::BlueHero,
+            RobotID::BlueEngineer => EndpointId::BlueEngineer,
+            RobotID::BlueInfantry3 => EndpointId::BlueInfantry3,
+            RobotID::BlueInfantry4 => EndpointId::BlueInfantry4,
+            RobotID::BlueInfantry5 => EndpointId::BlueInfantry5,
+            RobotID::BlueAerial => EndpointId::BlueAerial,
+            RobotID::BlueSentry => EndpointId::BlueSentry,
+            RobotID::BlueDart => EndpointId::BlueDart,
+            RobotID::BlueRadar => EndpointId::BlueRadar,
+            RobotID::BlueOutpost => EndpointId::BlueOutpost,
+            RobotID::BlueBase => EndpointId::BlueBase,
+        }
+    }
+}
+
+impl EndpointId {
+    // 端点为机器人时返回对应的机器人 ID
+    pub fn robot_id(self) -> Option<RobotID> {
+        match self {
+            EndpointId::RedHero => Some(RobotID::RedHero),
+            EndpointId::RedEngineer => Some(RobotID::RedEngineer),
+            EndpointId::RedInfantry3 => Some(RobotID::RedInfantry3),
+            EndpointId::RedInfantry4 => Some(RobotID::RedInfantry4),
+            EndpointId::RedInfantry5 => Some(RobotID::RedInfantry5),
+            EndpointId::RedAerial => Some(RobotID::RedAerial),
+            EndpointId::RedSentry => Some(RobotID::RedSentry),
+            EndpointId::RedDart => Some(RobotID::RedDart),
+            EndpointId::RedRadar => Some(RobotID::RedRadar),
+            EndpointId::RedOutpost => Some(RobotID::RedOutpost),
+            EndpointId::RedBase => Some(RobotID::RedBase),
+            EndpointId::BlueHero => Some(RobotID::BlueHero),
+            EndpointId::BlueEngineer => Some(RobotID::BlueEngineer),
+            EndpointId::BlueInfantry3 => Some(RobotID::BlueInfantry3),
+            EndpointId::BlueInfantry4 => Some(RobotID::BlueInfantry4),
+            EndpointId::BlueInfantry5 => Some(RobotID::BlueInfantry5),
+            EndpointId::BlueAerial => Some(RobotID::BlueAerial),
+            EndpointId::BlueSentry => Some(RobotID::BlueSentry),
+            EndpointId::BlueDart => Some(RobotID::BlueDart),
+            EndpointId::BlueRadar => Some(RobotID::BlueRadar),
+            EndpointId::BlueOutpost => Some(RobotID::BlueOutpost),
+            EndpointId::BlueBase => Some(RobotID::BlueBase),
+            _ => None,
+        }
+    }
+}
 #[deku_derive(DekuRead, DekuWrite)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -724,6 +924,20 @@ pub enum DartSelectionTarget {
     BaseRandomMoving = 3,
 }
 
+// 0x020A 飞镖发射站状态：1 关闭，2 正在开启或关闭中，0 已经开启
+#[deku_derive(DekuRead, DekuWrite)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[deku(id_type = "u8")]
+pub enum DartLaunchGate {
+    #[deku(id = "0")]
+    Open = 0,
+    #[deku(id = "1")]
+    Closed = 1,
+    #[deku(id = "2")]
+    Transitioning = 2,
+}
+
 #[deku_derive(DekuRead, DekuWrite)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
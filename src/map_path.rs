@@ -0,0 +1,220 @@
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{EndpointId, FieldPoint, RefereeFrameCmdData};
+
+// 0x0307 路径增量点数
+pub const MAP_PATH_DELTAS: usize = 49;
+
+// 0x0307 路径意图
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PathIntention {
+    // 到目标点攻击
+    Attack = 1,
+    // 到目标点防守
+    Defend = 2,
+    // 移动到目标点
+    Move = 3,
+}
+
+impl TryFrom<u8> for PathIntention {
+    type Error = MapPathError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Attack),
+            2 => Ok(Self::Defend),
+            3 => Ok(Self::Move),
+            _ => Err(MapPathError::UnknownIntention(value)),
+        }
+    }
+}
+
+impl From<PathIntention> for u8 {
+    fn from(value: PathIntention) -> Self {
+        value as u8
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MapPathError {
+    Empty,
+    // 第 n 个路径点不是有限值或超出 u16 分米范围
+    InvalidWaypoint(usize),
+    UnknownIntention(u8),
+    // 不是 0x0307 数据
+    NotMapPath,
+    // 增量数组长度不是 49
+    Malformed,
+}
+
+// 0x0307 选手端小地图路径：起点（分米）与 49 个分米增量。
+// 路径点过多或间距超过 i8 时按折线长度（取两轴较大值）等距重采样为 49 段。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MapPath {
+    intention: PathIntention,
+    start: (u16, u16),
+    delta_x: [i8; MAP_PATH_DELTAS],
+    delta_y: [i8; MAP_PATH_DELTAS],
+}
+
+impl MapPath {
+    // waypoints 为以米为单位的绝对坐标
+    pub fn from_waypoints(
+        waypoints: &[(f32, f32)],
+        intention: PathIntention,
+    ) -> Result<Self, MapPathError> {
+        let mut points: Vec<(i32, i32)> = Vec::with_capacity(waypoints.len());
+        for (i, &(x, y)) in waypoints.iter().enumerate() {
+            let (x, y) = FieldPoint::new(x, y)
+                .to_dm()
+                .map_err(|_| MapPathError::InvalidWaypoint(i))?;
+            let point = (i32::from(x), i32::from(y));
+            if points.last() != Some(&point) {
+                points.push(point);
+            }
+        }
+        let &start = points.first().ok_or(MapPathError::Empty)?;
+        let steps = split_segments(&points).unwrap_or_else(|| resample(&points));
+
+        let mut path = Self {
+            intention,
+            start: (start.0 as u16, start.1 as u16),
+            delta_x: [0; MAP_PATH_DELTAS],
+            delta_y: [0; MAP_PATH_DELTAS],
+        };
+        let mut current = start;
+        for (i, target) in steps.into_iter().enumerate() {
+            let dx = clamp_i8(target.0 - current.0);
+            let dy = clamp_i8(target.1 - current.1);
+            path.delta_x[i] = dx;
+            path.delta_y[i] = dy;
+            current = (current.0 + i32::from(dx), current.1 + i32::from(dy));
+        }
+        Ok(path)
+    }
+
+    pub fn from_cmd_data(cmd_data: &RefereeFrameCmdData) -> Result<Self, MapPathError> {
+        let RefereeFrameCmdData::MapPathData {
+            intention,
+            start_position_x,
+            start_position_y,
+            delta_x,
+            delta_y,
+            ..
+        } = cmd_data
+        else {
+            return Err(MapPathError::NotMapPath);
+        };
+        Ok(Self {
+            intention: PathIntention::try_from(*intention)?,
+            start: (*start_position_x, *start_position_y),
+            delta_x: delta_x
+                .as_slice()
+                .try_into()
+                .map_err(|_| MapPathError::Malformed)?,
+            delta_y: delta_y
+                .as_slice()
+                .try_into()
+                .map_err(|_| MapPathError::Malformed)?,
+        })
+    }
+
+    pub fn to_cmd_data(&self, sender_id: EndpointId) -> RefereeFrameCmdData {
+        RefereeFrameCmdData::MapPathData {
+            intention: self.intention.into(),
+            start_position_x: self.start.0,
+            start_position_y: self.start.1,
+            delta_x: self.delta_x.to_vec(),
+            delta_y: self.delta_y.to_vec(),
+            sender_id,
+        }
+    }
+
+    pub fn intention(&self) -> PathIntention {
+        self.intention
+    }
+
+    // 起点，单位分米
+    pub fn start_dm(&self) -> (u16, u16) {
+        self.start
+    }
+
+    pub fn deltas(&self) -> impl Iterator<Item = (i8, i8)> + '_ {
+        self.delta_x
+            .iter()
+            .copied()
+            .zip(self.delta_y.iter().copied())
+    }
+
+    // 还原绝对折线（米），跳过零增量
+    pub fn waypoints(&self) -> Vec<(f32, f32)> {
+        let mut current = (i32::from(self.start.0), i32::from(self.start.1));
+        let mut points = alloc::vec![dm_to_metres(current)];
+        for (dx, dy) in self.deltas().filter(|d| *d != (0, 0)) {
+            current = (current.0 + i32::from(dx), current.1 + i32::from(dy));
+            points.push(dm_to_metres(current));
+        }
+        points
+    }
+}
+
+fn dm_to_metres((x, y): (i32, i32)) -> (f32, f32) {
+    (x as f32 / 10.0, y as f32 / 10.0)
+}
+
+fn clamp_i8(value: i32) -> i8 {
+    value.clamp(i32::from(i8::MIN), i32::from(i8::MAX)) as i8
+}
+
+fn chebyshev(a: (i32, i32), b: (i32, i32)) -> i32 {
+    (b.0 - a.0).abs().max((b.1 - a.1).abs())
+}
+
+// 把过长的线段等分到 i8 范围内；总段数不超过 49 时返回各路径点
+fn split_segments(points: &[(i32, i32)]) -> Option<Vec<(i32, i32)>> {
+    let mut steps = Vec::new();
+    for pair in points.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        let pieces = (chebyshev(a, b) + i32::from(i8::MAX) - 1) / i32::from(i8::MAX);
+        if steps.len() + pieces as usize > MAP_PATH_DELTAS {
+            return None;
+        }
+        for k in 1..=pieces {
+            steps.push((
+                a.0 + (b.0 - a.0) * k / pieces,
+                a.1 + (b.1 - a.1) * k / pieces,
+            ));
+        }
+    }
+    Some(steps)
+}
+
+// 按折线长度等距取 49 个点，终点保持不变
+fn resample(points: &[(i32, i32)]) -> Vec<(i32, i32)> {
+    let total: i64 = points
+        .windows(2)
+        .map(|p| i64::from(chebyshev(p[0], p[1])))
+        .sum();
+    let mut steps = Vec::with_capacity(MAP_PATH_DELTAS);
+    let mut segment = 0;
+    let mut walked = 0i64;
+    for k in 1..=MAP_PATH_DELTAS as i64 {
+        let target = total * k / MAP_PATH_DELTAS as i64;
+        while segment + 2 < points.len()
+            && walked + i64::from(chebyshev(points[segment], points[segment + 1])) < target
+        {
+            walked += i64::from(chebyshev(points[segment], points[segment + 1]));
+            segment += 1;
+        }
+        let (a, b) = (points[segment], points[segment + 1]);
+        let len = i64::from(chebyshev(a, b)).max(1);
+        let t = (target - walked).min(len);
+        let lerp = |from: i32, to: i32| from + ((i64::from(to - from) * t) / len) as i32;
+        steps.push((lerp(a.0, b.0), lerp(a.1, b.1)));
+    }
+    steps
+}
@@ -0,0 +1,256 @@
+use alloc::collections::BTreeMap;
+use core::time::Duration;
+use deku::{DekuContainerWrite, DekuError};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{EndpointId, FieldPoint, RadarCmd, RefereeFrame, RefereeFrameCmdData, RobotID};
+
+// 0x0305 雷达小地图数据的发送频率上限
+pub const MAP_ROBOT_DATA_RATE_HZ: u32 = 10;
+
+// 一次双倍易伤触发请求的结果（由发送后的 0x020E 判定）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RadarTriggerOutcome {
+    Succeeded,
+    Failed,
+    // 超过 timeout 仍未收到 0x020E，结果未知；请求可能已生效
+    TimedOut,
+}
+
+// 雷达自主决策会话：跟踪 0x020E 中的双倍易伤机会与已发送的 0x0121 计数。
+// radar_cmd 需单调递增且每次仅 +1，仅在拥有机会、敌方未处于双倍易伤且无待确认请求时才生成触发帧。
+// 发送后不久到达的 0x020E 可能在服务器处理请求之前生成：机会数未变化时继续等待，
+// 直到发送 settle_delay 之后的 0x020E 判定失败；超过 timeout 仍未判定时报告超时。
+#[derive(Debug, Clone, PartialEq)]
+pub struct RadarCommander {
+    robot: RobotID,
+    last_sent: u8,
+    chances: Option<u8>,
+    enemy_in_double_vulnerability: bool,
+    settle_delay: Duration,
+    timeout: Duration,
+    // 待确认请求发出时的机会数与发送时刻
+    pending: Option<(u8, Duration)>,
+}
+
+impl RadarCommander {
+    // 0x020E 以 1Hz 发送：发送 1 秒后的上报应已反映请求结果
+    pub const DEFAULT_SETTLE_DELAY: Duration = Duration::from_secs(1);
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(3);
+
+    pub fn new(robot: RobotID) -> Self {
+        Self::resume(robot, 0)
+    }
+
+    // 从已知的上次发送计数恢复（例如雷达程序重启）
+    pub fn resume(robot: RobotID, last_sent: u8) -> Self {
+        Self {
+            robot,
+            last_sent,
+            chances: None,
+            enemy_in_double_vulnerability: false,
+            settle_delay: Self::DEFAULT_SETTLE_DELAY,
+            timeout: Self::DEFAULT_TIMEOUT,
+            pending: None,
+        }
+    }
+
+    // 机会数未变化的 0x020E 须在发送 settle_delay 之后到达才判定失败；超过 timeout 时报告超时
+    pub fn with_settle_delay(mut self, settle_delay: Duration, timeout: Duration) -> Self {
+        self.settle_delay = settle_delay;
+        self.timeout = timeout.max(settle_delay);
+        self
+    }
+
+    // 喂入一条命令数据；若判定了待确认请求，返回其结果。
+    // 任意数据都会检查超时，便于在 0x020E 丢失时恢复触发
+    pub fn update(
+        &mut self,
+        data: &RefereeFrameCmdData,
+        now: Duration,
+    ) -> Option<RadarTriggerOutcome> {
+        let RefereeFrameCmdData::RadarInfo {
+            double_vulnerability_chance,
+            enemy_in_double_vulnerability,
+        } = *data
+        else {
+            let (_, sent) = self.pending?;
+            if now.saturating_sub(sent) < self.timeout {
+                return None;
+            }
+            self.pending = None;
+            return Some(RadarTriggerOutcome::TimedOut);
+        };
+        self.chances = Some(double_vulnerability_chance);
+        self.enemy_in_double_vulnerability = enemy_in_double_vulnerability;
+        let (before, sent) = self.pending?;
+        let outcome = if enemy_in_double_vulnerability || double_vulnerability_chance < before {
+            RadarTriggerOutcome::Succeeded
+        } else if now.saturating_sub(sent) >= self.settle_delay {
+            RadarTriggerOutcome::Failed
+        } else {
+            return None;
+        };
+        self.pending = None;
+        Some(outcome)
+    }
+
+    // 剩余双倍易伤机会；尚未收到 0x020E 时为 None
+    pub fn chances(&self) -> Option<u8> {
+        self.chances
+    }
+
+    pub fn enemy_in_double_vulnerability(&self) -> bool {
+        self.enemy_in_double_vulnerability
+    }
+
+    pub fn last_sent(&self) -> u8 {
+        self.last_sent
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    pub fn can_trigger(&self) -> bool {
+        self.chances.is_some_and(|c| c > 0)
+            && !self.enemy_in_double_vulnerability
+            && self.pending.is_none()
+            && self.last_sent < u8::MAX
+    }
+
+    // 允许触发时生成发往服务器的 0x0301 / 0x0121 帧，并等待 0x020E 确认
+    pub fn trigger_frame(
+        &mut self,
+        seq: u8,
+        now: Duration,
+    ) -> Result<Option<RefereeFrame>, DekuError> {
+        if !self.can_trigger() {
+            return Ok(None);
+        }
+        let cmd = RadarCmd {
+            radar_cmd: self.last_sent + 1,
+        };
+        let frame = RefereeFrame::new(
+            seq,
+            RefereeFrameCmdData::RobotInteractionData {
+                data_cmd_id: 0x0121,
+                sender_id: self.robot.into(),
+                receiver_id: EndpointId::Server,
+                user_data: cmd.to_bytes()?,
+            },
+        )?;
+        self.last_sent = cmd.radar_cmd;
+        self.pending = self.chances.map(|c| (c, now));
+        Ok(Some(frame))
+    }
+}
+
+// 雷达小地图发布：按机器人 ID 记录对方机器人位置，生成 0x0305 帧。
+// 坐标由米转换为厘米并限制在 u16 范围内（越界由选手端显示在边缘）；
+// 未跟踪的机器人保持 (0, 0)，即“未发送此机器人坐标”。
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinimapPublisher {
+    radar: RobotID,
+    min_interval: Duration,
+    last_send: Option<Duration>,
+    tracked: BTreeMap<usize, FieldPoint>,
+}
+
+impl MinimapPublisher {
+    pub fn new(radar: RobotID) -> Self {
+        Self {
+            radar,
+            min_interval: Duration::from_secs(1) / MAP_ROBOT_DATA_RATE_HZ,
+            last_send: None,
+            tracked: BTreeMap::new(),
+        }
+    }
+
+    // rate_hz 超过协议上限时按上限处理
+    pub fn with_rate(mut self, rate_hz: u32) -> Self {
+        self.min_interval = Duration::from_secs(1) / rate_hz.clamp(1, MAP_ROBOT_DATA_RATE_HZ);
+        self
+    }
+
+    // 记录对方机器人位置；非对方或 0x0305 中没有对应字段的机器人、非有限坐标返回 false
+    pub fn track(&mut self, robot: RobotID, position: FieldPoint) -> bool {
+        let Some(slot) = self.slot(robot) else {
+            return false;
+        };
+        if !position.x.is_finite() || !position.y.is_finite() {
+            return false;
+        }
+        self.tracked.insert(slot, position);
+        true
+    }
+
+    pub fn untrack(&mut self, robot: RobotID) -> bool {
+        self.slot(robot)
+            .is_some_and(|slot| self.tracked.remove(&slot).is_some())
+    }
+
+    pub fn clear(&mut self) {
+        self.tracked.clear();
+    }
+
+    pub fn tracked_len(&self) -> usize {
+        self.tracked.len()
+    }
+
+    pub fn to_cmd_data(&self) -> RefereeFrameCmdData {
+        let [hero, engineer, infantry_3, infantry_4, infantry_5, sentry] =
+            core::array::from_fn(|slot| self.tracked.get(&slot).map_or((0, 0), |p| map_cm(*p)));
+        RefereeFrameCmdData::MapRobotData {
+            hero_position_x: hero.0,
+            hero_position_y: hero.1,
+            engineer_position_x: engineer.0,
+            engineer_position_y: engineer.1,
+            infantry_3_position_x: infantry_3.0,
+            infantry_3_position_y: infantry_3.1,
+            infantry_4_position_x: infantry_4.0,
+            infantry_4_position_y: infantry_4.1,
+            infantry_5_position_x: infantry_5.0,
+            infantry_5_position_y: infantry_5.1,
+            sentry_position_x: sentry.0,
+            sentry_position_y: sentry.1,
+        }
+    }
+
+    // 到达发送时隙时返回当前的 0x0305 数据
+    pub fn poll(&mut self, now: Duration) -> Option<RefereeFrameCmdData> {
+        if self
+            .last_send
+            .is_some_and(|t| now.saturating_sub(t) < self.min_interval)
+        {
+            return None;
+        }
+        self.last_send = Some(now);
+        Some(self.to_cmd_data())
+    }
+
+    // 0x0305 中对方机器人的字段序号
+    fn slot(&self, robot: RobotID) -> Option<usize> {
+        if robot.is_red() == self.radar.is_red() {
+            return None;
+        }
+        match (robot as u8) % 100 {
+            n @ 1..=5 => Some(usize::from(n) - 1),
+            7 => Some(5),
+            _ => None,
+        }
+    }
+}
+
+fn map_cm(position: FieldPoint) -> (u16, u16) {
+    let max = f32::from(u16::MAX) / 100.0;
+    let clamped = FieldPoint::new(position.x.clamp(0.0, max), position.y.clamp(0.0, max));
+    match clamped.to_cm() {
+        // (0, 0) 表示未发送，原点处的机器人向内偏移 1 厘米
+        Ok((0, 0)) => (1, 0),
+        Ok(cm) => cm,
+        Err(_) => (0, 0),
+    }
+}
@@ -0,0 +1,324 @@
+use alloc::collections::{BTreeMap, VecDeque};
+use alloc::vec::Vec;
+use core::time::Duration;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::robot_interaction::{MAX_INTERACTION_CONTENT_LEN, MAX_INTERACTION_RATE_HZ};
+use crate::robot_message::{check_id, check_len};
+use crate::{EndpointId, MessageError, RefereeFrameCmdData, RobotMessage};
+
+// 可靠传输默认使用的子内容 ID
+pub const RELIABLE_DATA_CMD_ID: u16 = 0x02F1;
+// 可靠传输头：类型 1 字节、会话号 1 字节、序号 2 字节、内层子内容 ID 2 字节（小端）
+pub const RELIABLE_HEADER_LEN: usize = 6;
+pub const RELIABLE_PAYLOAD_LEN: usize = MAX_INTERACTION_CONTENT_LEN - RELIABLE_HEADER_LEN;
+
+const KIND_DATA: u8 = 0;
+const KIND_ACK: u8 = 1;
+// 重复检测窗口：每个对端记住最近 64 个序号
+const WINDOW_BITS: u16 = 64;
+
+// 重试耗尽仍未被确认的消息
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DeliveryFailure {
+    pub receiver_id: EndpointId,
+    pub seq: u16,
+    pub data_cmd_id: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Outgoing {
+    receiver_id: EndpointId,
+    seq: u16,
+    data_cmd_id: u16,
+    payload: Vec<u8>,
+    attempts: u8,
+    next_send: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Accept {
+    Fresh,
+    Duplicate,
+    // 落在窗口之外，无法判断是否已交付
+    Stale,
+}
+
+// 接收窗口：对端会话号、最大序号与其之前 63 个序号的接收位图
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Window {
+    session: u8,
+    latest: u16,
+    mask: u64,
+}
+
+impl Window {
+    fn new(session: u8, seq: u16) -> Self {
+        Self {
+            session,
+            latest: seq,
+            mask: 1,
+        }
+    }
+
+    fn accept(&mut self, seq: u16) -> Accept {
+        let ahead = seq.wrapping_sub(self.latest) as i16;
+        if ahead > 0 {
+            let shift = ahead as u16;
+            self.mask = if shift >= WINDOW_BITS {
+                1
+            } else {
+                (self.mask << shift) | 1
+            };
+            self.latest = seq;
+            return Accept::Fresh;
+        }
+        let behind = ahead.unsigned_abs();
+        if behind >= WINDOW_BITS {
+            return Accept::Stale;
+        }
+        if self.mask & (1 << behind) != 0 {
+            return Accept::Duplicate;
+        }
+        self.mask |= 1 << behind;
+        Accept::Fresh
+    }
+}
+
+// 0x0301 机器人间消息的可靠传输层：按对端编号、确认、有限次重试与重复抑制，
+// 数据与确认共用同一发送时隙，整体不超过上行频率限制。
+// 发送方未收到确认时重发，接收方对重复序号只回确认不再交付，因此每条消息至多交付一次，
+// 在重试次数内送达时恰好交付一次。
+// 每帧携带发送方的会话号，对端会话号变化（重启）时重置其接收窗口；
+// 落在窗口之外的旧序号不确认也不交付，由发送方重试耗尽后报告为失败。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReliableLink {
+    local: EndpointId,
+    session: u8,
+    data_cmd_id: u16,
+    min_interval: Duration,
+    last_send: Option<Duration>,
+    retry_interval: Duration,
+    max_attempts: u8,
+    next_seq: BTreeMap<u16, u16>,
+    outbox: VecDeque<Outgoing>,
+    // 待回复的确认：对端、对端会话号、序号
+    acks: VecDeque<(EndpointId, u8, u16)>,
+    windows: BTreeMap<u16, Window>,
+    failures: Vec<DeliveryFailure>,
+}
+
+impl ReliableLink {
+    pub const DEFAULT_RETRY_INTERVAL: Duration = Duration::from_millis(300);
+    pub const DEFAULT_MAX_ATTEMPTS: u8 = 5;
+
+    // session 为本机会话号，每次启动须不同（如持久化的启动计数或随机数），
+    // 使对端能识别重启后从 0 开始的序号；rate_hz 超过协议上限时按上限处理
+    pub fn new(local: EndpointId, session: u8, rate_hz: u32) -> Self {
+        let rate = rate_hz.clamp(1, MAX_INTERACTION_RATE_HZ);
+        Self {
+            local,
+            session,
+            data_cmd_id: RELIABLE_DATA_CMD_ID,
+            min_interval: Duration::from_secs(1) / rate,
+            last_send: None,
+            retry_interval: Self::DEFAULT_RETRY_INTERVAL,
+            max_attempts: Self::DEFAULT_MAX_ATTEMPTS,
+            next_seq: BTreeMap::new(),
+            outbox: VecDeque::new(),
+            acks: VecDeque::new(),
+            windows: BTreeMap::new(),
+            failures: Vec::new(),
+        }
+    }
+
+    // data_cmd_id 须在 0x0200~0x02FF
+    pub fn with_data_cmd_id(mut self, data_cmd_id: u16) -> Result<Self, MessageError> {
+        check_id(data_cmd_id)?;
+        self.data_cmd_id = data_cmd_id;
+        Ok(self)
+    }
+
+    // 未确认时每隔 interval 重发，最多发送 max_attempts 次
+    pub fn with_retry(mut self, interval: Duration, max_attempts: u8) -> Self {
+        self.retry_interval = interval;
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn local(&self) -> EndpointId {
+        self.local
+    }
+
+    // 排队一条可靠消息，返回其序号；负载不超过 RELIABLE_PAYLOAD_LEN
+    pub fn send(
+        &mut self,
+        receiver_id: EndpointId,
+        data_cmd_id: u16,
+        payload: &[u8],
+    ) -> Result<u16, MessageError> {
+        check_id(data_cmd_id)?;
+        check_len(payload.len(), RELIABLE_PAYLOAD_LEN)?;
+        let next = self.next_seq.entry(receiver_id as u16).or_insert(0);
+        let seq = *next;
+        *next = next.wrapping_add(1);
+        self.outbox.push_back(Outgoing {
+            receiver_id,
+            seq,
+            data_cmd_id,
+            payload: payload.to_vec(),
+            attempts: 0,
+            next_send: Duration::ZERO,
+        });
+        Ok(seq)
+    }
+
+    pub fn send_message<M: RobotMessage>(
+        &mut self,
+        receiver_id: EndpointId,
+        message: &M,
+    ) -> Result<u16, MessageError> {
+        let payload = message.encode().map_err(MessageError::Encode)?;
+        check_len(payload.len(), M::MAX_LEN)?;
+        self.send(receiver_id, M::DATA_CMD_ID, &payload)
+    }
+
+    // 到达发送时隙时返回下一帧：优先发送确认，其次是到期的首发或重发
+    pub fn poll(&mut self, now: Duration) -> Option<RefereeFrameCmdData> {
+        self.drop_exhausted(now);
+        if self
+            .last_send
+            .is_some_and(|t| now.saturating_sub(t) < self.min_interval)
+        {
+            return None;
+        }
+        let frame = if let Some((peer, session, seq)) = self.acks.pop_front() {
+            self.frame(peer, KIND_ACK, session, seq, 0, &[])
+        } else {
+            let index = self.outbox.iter().position(|o| o.next_send <= now)?;
+            let mut out = self.outbox.remove(index)?;
+            out.attempts += 1;
+            out.next_send = now + self.retry_interval;
+            let frame = self.frame(
+                out.receiver_id,
+                KIND_DATA,
+                self.session,
+                out.seq,
+                out.data_cmd_id,
+                &out.payload,
+            );
+            // 重发排到队尾，避免单条消息占满时隙
+            self.outbox.push_back(out);
+            frame
+        };
+        self.last_send = Some(now);
+        Some(frame)
+    }
+
+    // 处理收到的 0x0301 数据：确认帧清除对应的待确认消息；
+    // 首次收到的数据帧还原为内层子内容 ID 的 0x0301 数据返回，重复帧只回确认，
+    // 窗口之外的旧帧既不确认也不交付
+    pub fn receive(&mut self, cmd_data: &RefereeFrameCmdData) -> Option<RefereeFrameCmdData> {
+        let RefereeFrameCmdData::RobotInteractionData {
+            data_cmd_id,
+            sender_id,
+            receiver_id,
+            user_data,
+        } = cmd_data
+        else {
+            return None;
+        };
+        if *data_cmd_id != self.data_cmd_id || *receiver_id != self.local {
+            return None;
+        }
+        let (&[kind, session, s0, s1, i0, i1], payload) = user_data.split_first_chunk()?;
+        let seq = u16::from_le_bytes([s0, s1]);
+        match kind {
+            KIND_ACK => {
+                // 本机重启前发出的数据的确认不能清除同序号的新消息
+                if session == self.session {
+                    self.outbox
+                        .retain(|o| !(o.receiver_id == *sender_id && o.seq == seq));
+                }
+                None
+            }
+            KIND_DATA => {
+                let accept = match self.windows.get_mut(&(*sender_id as u16)) {
+                    Some(window) if window.session == session => window.accept(seq),
+                    _ => {
+                        self.windows
+                            .insert(*sender_id as u16, Window::new(session, seq));
+                        Accept::Fresh
+                    }
+                };
+                if accept == Accept::Stale {
+                    return None;
+                }
+                // 同一对端的确认只保留当前会话
+                self.acks
+                    .retain(|(peer, s, _)| *peer != *sender_id || *s == session);
+                if !self.acks.contains(&(*sender_id, session, seq)) {
+                    self.acks.push_back((*sender_id, session, seq));
+                }
+                (accept == Accept::Fresh).then(|| RefereeFrameCmdData::RobotInteractionData {
+                    data_cmd_id: u16::from_le_bytes([i0, i1]),
+                    sender_id: *sender_id,
+                    receiver_id: *receiver_id,
+                    user_data: payload.to_vec(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    // 尚未被确认的消息数
+    pub fn in_flight(&self) -> usize {
+        self.outbox.len()
+    }
+
+    // 取出重试耗尽的消息
+    pub fn take_failures(&mut self) -> Vec<DeliveryFailure> {
+        core::mem::take(&mut self.failures)
+    }
+
+    fn drop_exhausted(&mut self, now: Duration) {
+        let max = self.max_attempts;
+        let failures = &mut self.failures;
+        self.outbox.retain(|o| {
+            let exhausted = o.attempts >= max && o.next_send <= now;
+            if exhausted {
+                failures.push(DeliveryFailure {
+                    receiver_id: o.receiver_id,
+                    seq: o.seq,
+                    data_cmd_id: o.data_cmd_id,
+                });
+            }
+            !exhausted
+        });
+    }
+
+    fn frame(
+        &self,
+        receiver_id: EndpointId,
+        kind: u8,
+        session: u8,
+        seq: u16,
+        inner_id: u16,
+        payload: &[u8],
+    ) -> RefereeFrameCmdData {
+        let mut user_data = Vec::with_capacity(RELIABLE_HEADER_LEN + payload.len());
+        user_data.push(kind);
+        user_data.push(session);
+        user_data.extend_from_slice(&seq.to_le_bytes());
+        user_data.extend_from_slice(&inner_id.to_le_bytes());
+        user_data.extend_from_slice(payload);
+        RefereeFrameCmdData::RobotInteractionData {
+            data_cmd_id: self.data_cmd_id,
+            sender_id: self.local,
+            receiver_id,
+            user_data,
+        }
+    }
+}
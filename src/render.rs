@@ -0,0 +1,592 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use std::f32::consts::PI;
+
+use crate::{
+    Figure, FigureColor, FigureOperateType, FigureShape, HudElement, HudScene, RobotID,
+    RobotInteractionUserData, SCREEN_HEIGHT, SCREEN_WIDTH, TextFigure,
+};
+
+// 选手端调色板（RGB）；己方主色随队伍变化
+pub fn palette(color: FigureColor, self_color: [u8; 3]) -> [u8; 3] {
+    match color {
+        FigureColor::SelfColor => self_color,
+        FigureColor::Yellow => [0xFF, 0xEB, 0x3B],
+        FigureColor::Green => [0x4C, 0xD9, 0x64],
+        FigureColor::Orange => [0xFF, 0x98, 0x00],
+        FigureColor::Magenta => [0xC2, 0x18, 0x5B],
+        FigureColor::Pink => [0xFF, 0x80, 0xAB],
+        FigureColor::Cyan => [0x00, 0xE5, 0xFF],
+        FigureColor::Black => [0x00, 0x00, 0x00],
+        FigureColor::White => [0xFF, 0xFF, 0xFF],
+    }
+}
+
+pub const RED_SELF_COLOR: [u8; 3] = [0xE5, 0x39, 0x35];
+pub const BLUE_SELF_COLOR: [u8; 3] = [0x1E, 0x88, 0xE5];
+
+// 字符图形的排版：字符格宽高均为字体大小，字形占格宽的 5/7，行距为 1.5 倍字体大小；
+// 文字起点为首字符左上角。栅格化使用内置 5×7 点阵字体，SVG 使用等宽字体。
+const GLYPH_COLUMNS: usize = 5;
+const GLYPH_ROWS: usize = 7;
+
+// RGBA 画布，坐标与选手端一致（原点左下角，y 向上）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canvas {
+    width: u16,
+    height: u16,
+    pixels: Vec<[u8; 4]>,
+}
+
+impl Canvas {
+    pub fn new(width: u16, height: u16, background: [u8; 4]) -> Self {
+        Self {
+            width,
+            height,
+            pixels: alloc::vec![background; usize::from(width) * usize::from(height)],
+        }
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    pub fn pixel(&self, x: u16, y: u16) -> Option<[u8; 4]> {
+        self.index(i32::from(x), i32::from(y))
+            .map(|i| self.pixels[i])
+    }
+
+    // 行优先、自顶向下的 RGBA 字节（图像文件的行序）
+    pub fn rgba(&self) -> Vec<u8> {
+        self.pixels.iter().flatten().copied().collect()
+    }
+
+    pub fn to_png(&self) -> Result<Vec<u8>, png::EncodingError> {
+        let mut out = Vec::new();
+        let mut encoder = png::Encoder::new(&mut out, self.width.into(), self.height.into());
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.rgba())?;
+        writer.finish()?;
+        Ok(out)
+    }
+
+    fn index(&self, x: i32, y: i32) -> Option<usize> {
+        let (w, h) = (i32::from(self.width), i32::from(self.height));
+        if !(0..w).contains(&x) || !(0..h).contains(&y) {
+            return None;
+        }
+        Some(((h - 1 - y) * w + x) as usize)
+    }
+
+    fn put(&mut self, x: i32, y: i32, rgb: [u8; 3]) {
+        if let Some(i) = self.index(x, y) {
+            self.pixels[i] = [rgb[0], rgb[1], rgb[2], 0xFF];
+        }
+    }
+
+    // 在包围盒内逐像素测试覆盖
+    fn fill(&mut self, bounds: [f32; 4], rgb: [u8; 3], covers: impl Fn(f32, f32) -> bool) {
+        let [x0, y0, x1, y1] = bounds;
+        let x0 = (x0.floor() as i32).max(0);
+        let y0 = (y0.floor() as i32).max(0);
+        let x1 = (x1.ceil() as i32).min(i32::from(self.width) - 1);
+        let y1 = (y1.ceil() as i32).min(i32::from(self.height) - 1);
+        for y in y0..=y1 {
+            for x in x0..=x1 {
+                if covers(x as f32, y as f32) {
+                    self.put(x, y, rgb);
+                }
+            }
+        }
+    }
+}
+
+// 离线 HUD 渲染器：收集图形与字符图形，按图层从低到高（同层按加入顺序）绘制，输出 PNG 或 SVG
+#[derive(Debug, Clone, PartialEq)]
+pub struct HudRenderer {
+    elements: Vec<HudElement>,
+    self_color: [u8; 3],
+    background: [u8; 4],
+}
+
+impl Default for HudRenderer {
+    fn default() -> Self {
+        Self {
+            elements: Vec::new(),
+            self_color: RED_SELF_COLOR,
+            background: [0, 0, 0, 0],
+        }
+    }
+}
+
+impl HudRenderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 按观看者所属队伍选择己方主色
+    pub fn for_robot(robot: RobotID) -> Self {
+        let self_color = if robot.is_red() {
+            RED_SELF_COLOR
+        } else {
+            BLUE_SELF_COLOR
+        };
+        Self {
+            self_color,
+            ..Self::default()
+        }
+    }
+
+    // 默认背景透明
+    pub fn with_background(mut self, rgba: [u8; 4]) -> Self {
+        self.background = rgba;
+        self
+    }
+
+    pub fn add(&mut self, element: impl Into<HudElement>) {
+        self.elements.push(element.into());
+    }
+
+    pub fn add_scene(&mut self, scene: &HudScene) {
+        self.elements.extend(scene.elements().copied());
+    }
+
+    // 加入一条 0x0301 图形子内容中新增 / 修改的图形；坐标在屏幕外或无法解析的字符图形忽略
+    pub fn add_user_data(&mut self, data: &RobotInteractionUserData) {
+        let drawn = |op: FigureOperateType| {
+            matches!(op, FigureOperateType::Add | FigureOperateType::Modify)
+        };
+        match data {
+            RobotInteractionUserData::Figure(f) => core::slice::from_ref(f),
+            RobotInteractionUserData::Figures2(f) => f.as_slice(),
+            RobotInteractionUserData::Figures5(f) => f.as_slice(),
+            RobotInteractionUserData::Figures7(f) => f.as_slice(),
+            RobotInteractionUserData::CustomCharacter { figure, data } => {
+                if drawn(figure.operate_type)
+                    && let Ok(text) = TextFigure::from_custom_character(figure, data)
+                {
+                    self.add(text);
+                }
+                return;
+            }
+            _ => return,
+        }
+        .iter()
+        .filter(|f| drawn(f.operate_type))
+        .filter_map(|f| Figure::try_from(f).ok())
+        .for_each(|f| self.elements.push(f.into()));
+    }
+
+    pub fn clear(&mut self) {
+        self.elements.clear();
+    }
+
+    pub fn render(&self) -> Canvas {
+        let mut canvas = Canvas::new(SCREEN_WIDTH, SCREEN_HEIGHT, self.background);
+        for element in self.ordered() {
+            self.draw(&mut canvas, element);
+        }
+        canvas
+    }
+
+    pub fn to_png(&self) -> Result<Vec<u8>, png::EncodingError> {
+        self.render().to_png()
+    }
+
+    pub fn to_svg(&self) -> String {
+        let (w, h) = (SCREEN_WIDTH, SCREEN_HEIGHT);
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\n"
+        );
+        let [r, g, b, a] = self.background;
+        if a > 0 {
+            let _ = writeln!(
+                svg,
+                "<rect width=\"{w}\" height=\"{h}\" fill=\"rgb({r},{g},{b})\" fill-opacity=\"{:.3}\"/>",
+                f32::from(a) / 255.0
+            );
+        }
+        for element in self.ordered() {
+            self.write_svg(&mut svg, element);
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    fn ordered(&self) -> Vec<&HudElement> {
+        let mut ordered: Vec<&HudElement> = self.elements.iter().collect();
+        ordered.sort_by_key(|e| e.layer());
+        ordered
+    }
+
+    fn draw(&self, canvas: &mut Canvas, element: &HudElement) {
+        let fig = element.figure();
+        let rgb = palette(fig.color, self.self_color);
+        let (sx, sy) = (f32::from(fig.start.x()), f32::from(fig.start.y()));
+        let half = (f32::from(fig.width) / 2.0).max(0.5);
+        match (element, fig.shape) {
+            (_, FigureShape::Line { end }) => {
+                let (ex, ey) = (f32::from(end.x()), f32::from(end.y()));
+                draw_segment(canvas, rgb, (sx, sy), (ex, ey), half);
+            }
+            (_, FigureShape::Rectangle { end }) => {
+                let (ex, ey) = (f32::from(end.x()), f32::from(end.y()));
+                for (a, b) in [
+                    ((sx, sy), (ex, sy)),
+                    ((ex, sy), (ex, ey)),
+                    ((ex, ey), (sx, ey)),
+                    ((sx, ey), (sx, sy)),
+                ] {
+                    draw_segment(canvas, rgb, a, b, half);
+                }
+            }
+            (_, FigureShape::Circle { radius }) => {
+                let r = f32::from(radius);
+                draw_ellipse(canvas, rgb, (sx, sy), (r, r), half, None);
+            }
+            (_, FigureShape::Ellipse { radius_x, radius_y }) => {
+                let radii = (f32::from(radius_x), f32::from(radius_y));
+                draw_ellipse(canvas, rgb, (sx, sy), radii, half, None);
+            }
+            (
+                _,
+                FigureShape::Arc {
+                    start_angle,
+                    end_angle,
+                    radius_x,
+                    radius_y,
+                },
+            ) => {
+                let radii = (f32::from(radius_x), f32::from(radius_y));
+                let span = Some((f32::from(start_angle), f32::from(end_angle)));
+                draw_ellipse(canvas, rgb, (sx, sy), radii, half, span);
+            }
+            (_, FigureShape::Int { font_size, value }) => {
+                draw_text(canvas, rgb, fig, font_size, &format!("{value}"));
+            }
+            (_, FigureShape::Float { font_size, value }) => {
+                draw_text(canvas, rgb, fig, font_size, &format!("{value:.3}"));
+            }
+            (HudElement::Text(text), FigureShape::Text { font_size, .. }) => {
+                draw_text(canvas, rgb, fig, font_size, text.text());
+            }
+            // 只有图形配置、没有字符内容的字符图形不绘制
+            (HudElement::Figure(_), FigureShape::Text { .. }) => {}
+        }
+    }
+
+    fn write_svg(&self, svg: &mut String, element: &HudElement) {
+        let fig = element.figure();
+        let [r, g, b] = palette(fig.color, self.self_color);
+        let stroke = format!(
+            "fill=\"none\" stroke=\"rgb({r},{g},{b})\" stroke-width=\"{}\"",
+            fig.width
+        );
+        let (sx, sy) = svg_point(fig.start.x(), fig.start.y());
+        let _ = match (element, fig.shape) {
+            (_, FigureShape::Line { end }) => {
+                let (ex, ey) = svg_point(end.x(), end.y());
+                writeln!(
+                    svg,
+                    "<line x1=\"{sx}\" y1=\"{sy}\" x2=\"{ex}\" y2=\"{ey}\" stroke-linecap=\"round\" {stroke}/>"
+                )
+            }
+            (_, FigureShape::Rectangle { end }) => {
+                let (ex, ey) = svg_point(end.x(), end.y());
+                writeln!(
+                    svg,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" {stroke}/>",
+                    sx.min(ex),
+                    sy.min(ey),
+                    sx.abs_diff(ex),
+                    sy.abs_diff(ey)
+                )
+            }
+            (_, FigureShape::Circle { radius }) => {
+                writeln!(
+                    svg,
+                    "<circle cx=\"{sx}\" cy=\"{sy}\" r=\"{radius}\" {stroke}/>"
+                )
+            }
+            (_, FigureShape::Ellipse { radius_x, radius_y }) => writeln!(
+                svg,
+                "<ellipse cx=\"{sx}\" cy=\"{sy}\" rx=\"{radius_x}\" ry=\"{radius_y}\" {stroke}/>"
+            ),
+            (
+                _,
+                FigureShape::Arc {
+                    start_angle,
+                    end_angle,
+                    radius_x,
+                    radius_y,
+                },
+            ) => {
+                let span = arc_span(f32::from(start_angle), f32::from(end_angle));
+                if span >= 360.0 {
+                    writeln!(
+                        svg,
+                        "<ellipse cx=\"{sx}\" cy=\"{sy}\" rx=\"{radius_x}\" ry=\"{radius_y}\" {stroke}/>"
+                    )
+                } else {
+                    let point = |angle: u16| {
+                        let t = f32::from(angle) * PI / 180.0;
+                        (
+                            f32::from(sx) + f32::from(radius_x) * t.sin(),
+                            f32::from(sy) - f32::from(radius_y) * t.cos(),
+                        )
+                    };
+                    let (ax, ay) = point(start_angle);
+                    let (bx, by) = point(end_angle);
+                    let large = u8::from(span > 180.0);
+                    writeln!(
+                        svg,
+                        "<path d=\"M {ax:.2} {ay:.2} A {radius_x} {radius_y} 0 {large} 1 {bx:.2} {by:.2}\" stroke-linecap=\"round\" {stroke}/>"
+                    )
+                }
+            }
+            (_, FigureShape::Int { font_size, value }) => {
+                write_svg_text(svg, [r, g, b], fig, font_size, &format!("{value}"))
+            }
+            (_, FigureShape::Float { font_size, value }) => {
+                write_svg_text(svg, [r, g, b], fig, font_size, &format!("{value:.3}"))
+            }
+            (HudElement::Text(text), FigureShape::Text { font_size, .. }) => {
+                write_svg_text(svg, [r, g, b], fig, font_size, text.text())
+            }
+            (HudElement::Figure(_), FigureShape::Text { .. }) => Ok(()),
+        };
+    }
+}
+
+// 选手端坐标转为 SVG 坐标（y 向下）
+fn svg_point(x: u16, y: u16) -> (u16, u16) {
+    (x, SCREEN_HEIGHT - 1 - y)
+}
+
+fn write_svg_text(
+    svg: &mut String,
+    [r, g, b]: [u8; 3],
+    fig: &Figure,
+    font_size: u16,
+    text: &str,
+) -> core::fmt::Result {
+    let (x, y) = svg_point(fig.start.x(), fig.start.y());
+    write!(
+        svg,
+        "<text x=\"{x}\" y=\"{y}\" font-family=\"monospace\" font-size=\"{font_size}\" dominant-baseline=\"hanging\" fill=\"rgb({r},{g},{b})\">"
+    )?;
+    for (i, line) in text.split('\n').enumerate() {
+        let dy = if i == 0 { 0 } else { line_height(font_size) };
+        write!(svg, "<tspan x=\"{x}\" dy=\"{dy}\">")?;
+        for c in line.chars() {
+            match c {
+                '<' => svg.push_str("&lt;"),
+                '>' => svg.push_str("&gt;"),
+                '&' => svg.push_str("&amp;"),
+                _ => svg.push(c),
+            }
+        }
+        svg.push_str("</tspan>");
+    }
+    svg.push_str("</text>\n");
+    Ok(())
+}
+
+fn line_height(font_size: u16) -> u16 {
+    font_size + font_size / 2
+}
+
+fn draw_segment(canvas: &mut Canvas, rgb: [u8; 3], a: (f32, f32), b: (f32, f32), half: f32) {
+    let bounds = [
+        a.0.min(b.0) - half,
+        a.1.min(b.1) - half,
+        a.0.max(b.0) + half,
+        a.1.max(b.1) + half,
+    ];
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let len2 = dx * dx + dy * dy;
+    canvas.fill(bounds, rgb, |x, y| {
+        let t = if len2 > 0.0 {
+            (((x - a.0) * dx + (y - a.1) * dy) / len2).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let (px, py) = (a.0 + t * dx - x, a.1 + t * dy - y);
+        px * px + py * py <= half * half
+    });
+}
+
+// 椭圆环（可选角度范围）：以隐式函数的一阶近似估计像素到曲线的距离
+fn draw_ellipse(
+    canvas: &mut Canvas,
+    rgb: [u8; 3],
+    (cx, cy): (f32, f32),
+    (rx, ry): (f32, f32),
+    half: f32,
+    span: Option<(f32, f32)>,
+) {
+    let bounds = [
+        cx - rx - half,
+        cy - ry - half,
+        cx + rx + half,
+        cy + ry + half,
+    ];
+    canvas.fill(bounds, rgb, |x, y| {
+        let (dx, dy) = (x - cx, y - cy);
+        if let Some((start, end)) = span {
+            let angle = dx.atan2(dy).to_degrees().rem_euclid(360.0);
+            if arc_span(start, angle) > arc_span(start, end) {
+                return false;
+            }
+        }
+        if rx <= 0.0 || ry <= 0.0 {
+            // 退化为线段
+            return (dx.abs() - rx).max(0.0).hypot((dy.abs() - ry).max(0.0)) <= half;
+        }
+        let f = (dx / rx).powi(2) + (dy / ry).powi(2) - 1.0;
+        let grad = (2.0 * dx / (rx * rx)).hypot(2.0 * dy / (ry * ry));
+        grad > 0.0 && f.abs() / grad <= half
+    });
+}
+
+// 从 start 顺时针转到 end 的角度（0-360；相等的非零区间视为整圈）
+fn arc_span(start: f32, end: f32) -> f32 {
+    if end - start >= 360.0 {
+        360.0
+    } else {
+        (end - start).rem_euclid(360.0)
+    }
+}
+
+fn draw_text(canvas: &mut Canvas, rgb: [u8; 3], fig: &Figure, font_size: u16, text: &str) {
+    let size = i32::from(font_size.max(1));
+    let glyph_w = (size * GLYPH_COLUMNS as i32 / GLYPH_ROWS as i32).max(1);
+    let (x0, top) = (i32::from(fig.start.x()), i32::from(fig.start.y()));
+    for (line_no, line) in text.split('\n').enumerate() {
+        let line_top = top - line_no as i32 * i32::from(line_height(font_size));
+        for (col, c) in line.bytes().enumerate() {
+            let Some(glyph) = glyph(c) else { continue };
+            let left = x0 + col as i32 * size;
+            for v in 0..size {
+                let row = (v * GLYPH_ROWS as i32 / size) as usize;
+                for u in 0..glyph_w {
+                    let column = (u * GLYPH_COLUMNS as i32 / glyph_w) as usize;
+                    if (glyph[column] >> row) & 1 == 1 {
+                        canvas.put(left + u, line_top - v, rgb);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// 5×7 点阵：每字节一列，最低位为顶行
+fn glyph(c: u8) -> Option<&'static [u8; GLYPH_COLUMNS]> {
+    FONT.get(usize::from(c.checked_sub(b' ')?))
+}
+
+const FONT: [[u8; GLYPH_COLUMNS]; 95] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00], // ' '
+    [0x00, 0x00, 0x5F, 0x00, 0x00], // !
+    [0x00, 0x07, 0x00, 0x07, 0x00], // "
+    [0x14, 0x7F, 0x14, 0x7F, 0x14], // #
+    [0x24, 0x2A, 0x7F, 0x2A, 0x12], // $
+    [0x23, 0x13, 0x08, 0x64, 0x62], // %
+    [0x36, 0x49, 0x55, 0x22, 0x50], // &
+    [0x00, 0x05, 0x03, 0x00, 0x00], // '
+    [0x00, 0x1C, 0x22, 0x41, 0x00], // (
+    [0x00, 0x41, 0x22, 0x1C, 0x00], // )
+    [0x08, 0x2A, 0x1C, 0x2A, 0x08], // *
+    [0x08, 0x08, 0x3E, 0x08, 0x08], // +
+    [0x00, 0x50, 0x30, 0x00, 0x00], // ,
+    [0x08, 0x08, 0x08, 0x08, 0x08], // -
+    [0x00, 0x60, 0x60, 0x00, 0x00], // .
+    [0x20, 0x10, 0x08, 0x04, 0x02], // /
+    [0x3E, 0x51, 0x49, 0x45, 0x3E], // 0
+    [0x00, 0x42, 0x7F, 0x40, 0x00], // 1
+    [0x42, 0x61, 0x51, 0x49, 0x46], // 2
+    [0x21, 0x41, 0x45, 0x4B, 0x31], // 3
+    [0x18, 0x14, 0x12, 0x7F, 0x10], // 4
+    [0x27, 0x45, 0x45, 0x45, 0x39], // 5
+    [0x3C, 0x4A, 0x49, 0x49, 0x30], // 6
+    [0x01, 0x71, 0x09, 0x05, 0x03], // 7
+    [0x36, 0x49, 0x49, 0x49, 0x36], // 8
+    [0x06, 0x49, 0x49, 0x29, 0x1E], // 9
+    [0x00, 0x36, 0x36, 0x00, 0x00], // :
+    [0x00, 0x56, 0x36, 0x00, 0x00], // ;
+    [0x08, 0x14, 0x22, 0x41, 0x00], // <
+    [0x14, 0x14, 0x14, 0x14, 0x14], // =
+    [0x00, 0x41, 0x22, 0x14, 0x08], // >
+    [0x02, 0x01, 0x51, 0x09, 0x06], // ?
+    [0x32, 0x49, 0x79, 0x41, 0x3E], // @
+    [0x7E, 0x11, 0x11, 0x11, 0x7E], // A
+    [0x7F, 0x49, 0x49, 0x49, 0x36], // B
+    [0x3E, 0x41, 0x41, 0x41, 0x22], // C
+    [0x7F, 0x41, 0x41, 0x22, 0x1C], // D
+    [0x7F, 0x49, 0x49, 0x49, 0x41], // E
+    [0x7F, 0x09, 0x09, 0x09, 0x01], // F
+    [0x3E, 0x41, 0x49, 0x49, 0x7A], // G
+    [0x7F, 0x08, 0x08, 0x08, 0x7F], // H
+    [0x00, 0x41, 0x7F, 0x41, 0x00], // I
+    [0x20, 0x40, 0x41, 0x3F, 0x01], // J
+    [0x7F, 0x08, 0x14, 0x22, 0x41], // K
+    [0x7F, 0x40, 0x40, 0x40, 0x40], // L
+    [0x7F, 0x02, 0x0C, 0x02, 0x7F], // M
+    [0x7F, 0x04, 0x08, 0x10, 0x7F], // N
+    [0x3E, 0x41, 0x41, 0x41, 0x3E], // O
+    [0x7F, 0x09, 0x09, 0x09, 0x06], // P
+    [0x3E, 0x41, 0x51, 0x21, 0x5E], // Q
+    [0x7F, 0x09, 0x19, 0x29, 0x46], // R
+    [0x46, 0x49, 0x49, 0x49, 0x31], // S
+    [0x01, 0x01, 0x7F, 0x01, 0x01], // T
+    [0x3F, 0x40, 0x40, 0x40, 0x3F], // U
+    [0x1F, 0x20, 0x40, 0x20, 0x1F], // V
+    [0x3F, 0x40, 0x38, 0x40, 0x3F], // W
+    [0x63, 0x14, 0x08, 0x14, 0x63], // X
+    [0x07, 0x08, 0x70, 0x08, 0x07], // Y
+    [0x61, 0x51, 0x49, 0x45, 0x43], // Z
+    [0x00, 0x7F, 0x41, 0x41, 0x00], // [
+    [0x02, 0x04, 0x08, 0x10, 0x20], // \
+    [0x00, 0x41, 0x41, 0x7F, 0x00], // ]
+    [0x04, 0x02, 0x01, 0x02, 0x04], // ^
+    [0x40, 0x40, 0x40, 0x40, 0x40], // _
+    [0x00, 0x01, 0x02, 0x04, 0x00], // `
+    [0x20, 0x54, 0x54, 0x54, 0x78], // a
+    [0x7F, 0x48, 0x44, 0x44, 0x38], // b
+    [0x38, 0x44, 0x44, 0x44, 0x20], // c
+    [0x38, 0x44, 0x44, 0x48, 0x7F], // d
+    [0x38, 0x54, 0x54, 0x54, 0x18], // e
+    [0x08, 0x7E, 0x09, 0x01, 0x02], // f
+    [0x0C, 0x52, 0x52, 0x52, 0x3E], // g
+    [0x7F, 0x08, 0x04, 0x04, 0x78], // h
+    [0x00, 0x44, 0x7D, 0x40, 0x00], // i
+    [0x20, 0x40, 0x44, 0x3D, 0x00], // j
+    [0x7F, 0x10, 0x28, 0x44, 0x00], // k
+    [0x00, 0x41, 0x7F, 0x40, 0x00], // l
+    [0x7C, 0x04, 0x18, 0x04, 0x78], // m
+    [0x7C, 0x08, 0x04, 0x04, 0x78], // n
+    [0x38, 0x44, 0x44, 0x44, 0x38], // o
+    [0x7C, 0x14, 0x14, 0x14, 0x08], // p
+    [0x08, 0x14, 0x14, 0x18, 0x7C], // q
+    [0x7C, 0x08, 0x04, 0x04, 0x08], // r
+    [0x48, 0x54, 0x54, 0x54, 0x20], // s
+    [0x04, 0x3F, 0x44, 0x40, 0x20], // t
+    [0x3C, 0x40, 0x40, 0x20, 0x7C], // u
+    [0x1C, 0x20, 0x40, 0x20, 0x1C], // v
+    [0x3C, 0x40, 0x30, 0x40, 0x3C], // w
+    [0x44, 0x28, 0x10, 0x28, 0x44], // x
+    [0x0C, 0x50, 0x50, 0x50, 0x3C], // y
+    [0x44, 0x64, 0x54, 0x4C, 0x44], // z
+    [0x00, 0x08, 0x36, 0x41, 0x00], // {
+    [0x00, 0x00, 0x7F, 0x00, 0x00], // |
+    [0x00, 0x41, 0x36, 0x08, 0x00], // }
+    [0x08, 0x04, 0x08, 0x10, 0x08], // ~
+];
@@ -1,27 +1,34 @@
-use deku::deku_derive;
+use alloc::vec::Vec;
+use deku::{DekuContainerWrite, DekuError, deku_derive};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-// 0x0301 子内容：哨兵自主决策指令（0x0120），位域结构（4 字节）
+use crate::{EndpointId, RefereeFrameCmdData};
+
+// 0x0301 机器人交互数据：内容数据段最大 112 字节，发送频率上限 30Hz
+pub const MAX_INTERACTION_CONTENT_LEN: usize = 112;
+pub const MAX_INTERACTION_RATE_HZ: u32 = 30;
+
+// 0x0301 子内容：哨兵自主决策指令（0x0120），位域结构（4 字节），按小端 u32 从最低位开始排列
 #[deku_derive(DekuRead, DekuWrite)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SentryCmd {
-    #[deku(bits = 1)]
+    #[deku(bits = 1, bit_order = "lsb")]
     pub confirm_revive: bool,
-    #[deku(bits = 1)]
+    #[deku(bits = 1, bit_order = "lsb")]
     pub confirm_immediate_revive: bool,
     // bit 2-12：哨兵将要兑换的发弹量值（单调递增）
-    #[deku(bits = 11)]
+    #[deku(bits = 11, bit_order = "lsb")]
     pub exchange_projectile_allowance: u16,
     // bit 13-16：远程兑换发弹量的请求次数（单调递增，每次 +1）
-    #[deku(bits = 4)]
+    #[deku(bits = 4, bit_order = "lsb")]
     pub remote_exchange_projectile_count: u8,
     // bit 17-20：远程兑换血量的请求次数（单调递增，每次 +1）
-    #[deku(bits = 4)]
+    #[deku(bits = 4, bit_order = "lsb")]
     pub remote_exchange_hp_count: u8,
     // bit 21-31：保留
-    #[deku(bits = 11, temp, temp_value = "u16::default()")]
+    #[deku(bits = 11, bit_order = "lsb", temp, temp_value = "u16::default()")]
     pub reserved: u16,
 }
 
@@ -58,7 +65,7 @@ pub struct InteractionLayerDelete {
 #[deku_derive(DekuRead, DekuWrite)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[deku(id_type = "u8", bits = 3)]
+#[deku(id_type = "u8", bits = 3, bit_order = "lsb")]
 pub enum FigureOperateType {
     #[deku(id = "0")]
     None,
@@ -73,7 +80,7 @@ pub enum FigureOperateType {
 #[deku_derive(DekuRead, DekuWrite)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[deku(id_type = "u8", bits = 3)]
+#[deku(id_type = "u8", bits = 3, bit_order = "lsb")]
 pub enum FigureType {
     #[deku(id = "0")]
     Line,
@@ -96,7 +103,7 @@ pub enum FigureType {
 #[deku_derive(DekuRead, DekuWrite)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[deku(id_type = "u8", bits = 4)]
+#[deku(id_type = "u8", bits = 4, bit_order = "lsb")]
 pub enum FigureColor {
     #[deku(id = "0")]
     SelfColor,
@@ -118,7 +125,8 @@ pub enum FigureColor {
     White,
 }
 
-// 单个图形（15 字节）：3 字节图形名 + 三段 32bit 位域
+// 单个图形（15 字节）：3 字节图形名 + 三段 32bit 位域。
+// 每段按小端 u32 存放，位域从最低位开始排列（C 结构体位域布局）
 #[deku_derive(DekuRead, DekuWrite)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -127,26 +135,26 @@ pub struct InteractionFigure {
     // 配置 1（32bit）
     pub operate_type: FigureOperateType,
     pub figure_type: FigureType,
-    #[deku(bits = 4)]
+    #[deku(bits = 4, bit_order = "lsb")]
     pub layer: u8,
     pub color: FigureColor,
-    #[deku(bits = 9)]
+    #[deku(bits = 9, bit_order = "lsb")]
     pub details_a: u16,
-    #[deku(bits = 9)]
+    #[deku(bits = 9, bit_order = "lsb")]
     pub details_b: u16,
     // 配置 2（32bit）
-    #[deku(bits = 10)]
+    #[deku(bits = 10, bit_order = "lsb")]
     pub width: u16,
-    #[deku(bits = 11)]
+    #[deku(bits = 11, bit_order = "lsb")]
     pub start_x: u16,
-    #[deku(bits = 11)]
+    #[deku(bits = 11, bit_order = "lsb")]
     pub start_y: u16,
     // 配置 3（32bit）
-    #[deku(bits = 10)]
+    #[deku(bits = 10, bit_order = "lsb")]
     pub details_c: u16,
-    #[deku(bits = 11)]
+    #[deku(bits = 11, bit_order = "lsb")]
     pub details_d: u16,
-    #[deku(bits = 11)]
+    #[deku(bits = 11, bit_order = "lsb")]
     pub details_e: u16,
 }
 
@@ -184,3 +192,58 @@ pub enum RobotInteractionUserData {
     #[deku(id = "0x0121")]
     RadarCmd(RadarCmd),
 }
+
+impl RobotInteractionUserData {
+    // 对应的 0x0301 data_cmd_id
+    pub fn data_cmd_id(&self) -> u16 {
+        match self {
+            RobotInteractionUserData::LayerDelete(_) => 0x0100,
+            RobotInteractionUserData::Figure(_) => 0x0101,
+            RobotInteractionUserData::Figures2(_) => 0x0102,
+            RobotInteractionUserData::Figures5(_) => 0x0103,
+            RobotInteractionUserData::Figures7(_) => 0x0104,
+            RobotInteractionUserData::CustomCharacter { .. } => 0x0110,
+            RobotInteractionUserData::SentryCmd(_) => 0x0120,
+            RobotInteractionUserData::RadarCmd(_) => 0x0121,
+        }
+    }
+
+    // 编码为 0x0301 的 user_data（不含 data_cmd_id）
+    pub fn payload_bytes(&self) -> Result<Vec<u8>, DekuError> {
+        let figures = |figs: &[InteractionFigure]| -> Result<Vec<u8>, DekuError> {
+            let mut v = Vec::with_capacity(figs.len() * 15);
+            for f in figs {
+                v.extend_from_slice(&f.to_bytes()?);
+            }
+            Ok(v)
+        };
+        match self {
+            RobotInteractionUserData::LayerDelete(d) => d.to_bytes(),
+            RobotInteractionUserData::Figure(f) => f.to_bytes(),
+            RobotInteractionUserData::Figures2(f) => figures(f),
+            RobotInteractionUserData::Figures5(f) => figures(f),
+            RobotInteractionUserData::Figures7(f) => figures(f),
+            RobotInteractionUserData::CustomCharacter { figure, data } => {
+                let mut v = figure.to_bytes()?;
+                v.extend_from_slice(data);
+                Ok(v)
+            }
+            RobotInteractionUserData::SentryCmd(c) => c.to_bytes(),
+            RobotInteractionUserData::RadarCmd(c) => c.to_bytes(),
+        }
+    }
+
+    // 封装为 0x0301 命令数据
+    pub fn to_cmd_data(
+        &self,
+        sender_id: EndpointId,
+        receiver_id: EndpointId,
+    ) -> Result<RefereeFrameCmdData, DekuError> {
+        Ok(RefereeFrameCmdData::RobotInteractionData {
+            data_cmd_id: self.data_cmd_id(),
+            sender_id,
+            receiver_id,
+            user_data: self.payload_bytes()?,
+        })
+    }
+}
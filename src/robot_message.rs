@@ -0,0 +1,181 @@
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+use deku::DekuError;
+
+use crate::robot_interaction::MAX_INTERACTION_CONTENT_LEN;
+use crate::{EndpointId, RefereeFrameCmdData, RobotID};
+
+// 0x0301 中留给队伍自定义机器人间通信的子内容 ID
+pub const ROBOT_MESSAGE_IDS: RangeInclusive<u16> = 0x0200..=0x02FF;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageError {
+    // 不是 0x0301 机器人交互数据
+    NotInteraction,
+    // data_cmd_id 不在 0x0200~0x02FF
+    IdOutOfRange(u16),
+    // 同一 ID 重复注册
+    DuplicateId(u16),
+    // 没有注册该 ID 的消息
+    UnknownId(u16),
+    // 负载长度超过上限
+    TooLong(usize),
+    Encode(DekuError),
+    Decode(DekuError),
+}
+
+// 队伍自定义的机器人间消息（0x0301，data_cmd_id 0x0200~0x02FF）
+pub trait RobotMessage: Sized {
+    const DATA_CMD_ID: u16;
+    // 负载最大长度，不得超过协议的 112 字节
+    const MAX_LEN: usize = MAX_INTERACTION_CONTENT_LEN;
+
+    fn encode(&self) -> Result<Vec<u8>, DekuError>;
+    fn decode(bytes: &[u8]) -> Result<Self, DekuError>;
+
+    // 编码为 0x0301 数据，校验 ID 范围与负载长度
+    fn to_cmd_data(
+        &self,
+        sender_id: EndpointId,
+        receiver_id: EndpointId,
+    ) -> Result<RefereeFrameCmdData, MessageError> {
+        check_id(Self::DATA_CMD_ID)?;
+        let user_data = self.encode().map_err(MessageError::Encode)?;
+        check_len(user_data.len(), Self::MAX_LEN)?;
+        Ok(RefereeFrameCmdData::RobotInteractionData {
+            data_cmd_id: Self::DATA_CMD_ID,
+            sender_id,
+            receiver_id,
+            user_data,
+        })
+    }
+
+    // 从 0x0301 数据解码；data_cmd_id 不匹配时返回 UnknownId
+    fn from_cmd_data(cmd_data: &RefereeFrameCmdData) -> Result<Self, MessageError> {
+        let (data_cmd_id, _, user_data) = robot_message(cmd_data)?;
+        if data_cmd_id != Self::DATA_CMD_ID {
+            return Err(MessageError::UnknownId(data_cmd_id));
+        }
+        check_len(user_data.len(), Self::MAX_LEN)?;
+        Self::decode(user_data).map_err(MessageError::Decode)
+    }
+}
+
+// 消息编码后的位数，供派生宏在编译期检查负载长度；自定义的嵌套类型需手动实现
+pub trait WireSize {
+    const BITS: usize;
+}
+
+macro_rules! wire_size {
+    ($($ty:ty),*) => {
+        $(impl WireSize for $ty {
+            const BITS: usize = core::mem::size_of::<$ty>() * 8;
+        })*
+    };
+}
+
+wire_size!(u8, i8, u16, i16, u32, i32, u64, i64, f32, f64);
+
+impl WireSize for bool {
+    const BITS: usize = 8;
+}
+
+impl<T: WireSize, const N: usize> WireSize for [T; N] {
+    const BITS: usize = T::BITS * N;
+}
+
+impl WireSize for RobotID {
+    const BITS: usize = 8;
+}
+
+impl WireSize for EndpointId {
+    const BITS: usize = 16;
+}
+
+type Handler<'a> = Box<dyn FnMut(EndpointId, &[u8]) -> Result<(), MessageError> + 'a>;
+
+// 按 data_cmd_id 将收到的机器人间消息解码为注册的类型并分发给处理函数
+#[derive(Default)]
+pub struct MessageRegistry<'a> {
+    handlers: BTreeMap<u16, Handler<'a>>,
+}
+
+impl<'a> MessageRegistry<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 注册消息类型及其处理函数（参数为发送方与解码后的消息）
+    pub fn register<M: RobotMessage + 'a>(
+        &mut self,
+        mut handler: impl FnMut(EndpointId, M) + 'a,
+    ) -> Result<(), MessageError> {
+        let id = M::DATA_CMD_ID;
+        check_id(id)?;
+        if self.handlers.contains_key(&id) {
+            return Err(MessageError::DuplicateId(id));
+        }
+        let handler: Handler<'a> = Box::new(move |sender, bytes| {
+            check_len(bytes.len(), M::MAX_LEN)?;
+            handler(sender, M::decode(bytes).map_err(MessageError::Decode)?);
+            Ok(())
+        });
+        self.handlers.insert(id, handler);
+        Ok(())
+    }
+
+    pub fn is_registered(&self, data_cmd_id: u16) -> bool {
+        self.handlers.contains_key(&data_cmd_id)
+    }
+
+    // 解码并分发一条 0x0301 数据，成功时返回其 data_cmd_id
+    pub fn dispatch(&mut self, cmd_data: &RefereeFrameCmdData) -> Result<u16, MessageError> {
+        let (data_cmd_id, sender_id, user_data) = robot_message(cmd_data)?;
+        let handler = self
+            .handlers
+            .get_mut(&data_cmd_id)
+            .ok_or(MessageError::UnknownId(data_cmd_id))?;
+        handler(sender_id, user_data)?;
+        Ok(data_cmd_id)
+    }
+}
+
+impl core::fmt::Debug for MessageRegistry<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.handlers.keys()).finish()
+    }
+}
+
+pub(crate) fn check_id(data_cmd_id: u16) -> Result<(), MessageError> {
+    if ROBOT_MESSAGE_IDS.contains(&data_cmd_id) {
+        Ok(())
+    } else {
+        Err(MessageError::IdOutOfRange(data_cmd_id))
+    }
+}
+
+pub(crate) fn check_len(len: usize, max: usize) -> Result<(), MessageError> {
+    if len > max.min(MAX_INTERACTION_CONTENT_LEN) {
+        Err(MessageError::TooLong(len))
+    } else {
+        Ok(())
+    }
+}
+
+// 取出 0x0301 机器人间消息的 ID、发送方与负载
+fn robot_message(cmd_data: &RefereeFrameCmdData) -> Result<(u16, EndpointId, &[u8]), MessageError> {
+    let RefereeFrameCmdData::RobotInteractionData {
+        data_cmd_id,
+        sender_id,
+        user_data,
+        ..
+    } = cmd_data
+    else {
+        return Err(MessageError::NotInteraction);
+    };
+    check_id(*data_cmd_id)?;
+    check_len(user_data.len(), MAX_INTERACTION_CONTENT_LEN)?;
+    Ok((*data_cmd_id, *sender_id, user_data))
+}
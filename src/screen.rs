@@ -0,0 +1,157 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// 选手端 UI 坐标系：1920 × 1080，原点位于屏幕左下角，x 向右、y 向上
+pub const SCREEN_WIDTH: u16 = 1920;
+pub const SCREEN_HEIGHT: u16 = 1080;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ScreenError {
+    OutOfRange,
+}
+
+// 越界坐标的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BoundsPolicy {
+    // 截断到屏幕边缘
+    Clamp,
+    #[default]
+    Error,
+}
+
+// 屏幕内的一个像素坐标，构造时保证 x < 1920、y < 1080
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScreenPoint {
+    x: u16,
+    y: u16,
+}
+
+impl ScreenPoint {
+    pub const BOTTOM_LEFT: ScreenPoint = ScreenPoint { x: 0, y: 0 };
+    pub const BOTTOM_RIGHT: ScreenPoint = ScreenPoint {
+        x: SCREEN_WIDTH - 1,
+        y: 0,
+    };
+    pub const TOP_LEFT: ScreenPoint = ScreenPoint {
+        x: 0,
+        y: SCREEN_HEIGHT - 1,
+    };
+    pub const TOP_RIGHT: ScreenPoint = ScreenPoint {
+        x: SCREEN_WIDTH - 1,
+        y: SCREEN_HEIGHT - 1,
+    };
+    pub const CENTER: ScreenPoint = ScreenPoint {
+        x: SCREEN_WIDTH / 2,
+        y: SCREEN_HEIGHT / 2,
+    };
+
+    pub fn new(x: u16, y: u16) -> Result<Self, ScreenError> {
+        Self::with_policy(x.into(), y.into(), BoundsPolicy::Error)
+    }
+
+    pub fn clamped(x: i32, y: i32) -> Self {
+        Self {
+            x: x.clamp(0, i32::from(SCREEN_WIDTH) - 1) as u16,
+            y: y.clamp(0, i32::from(SCREEN_HEIGHT) - 1) as u16,
+        }
+    }
+
+    pub fn with_policy(x: i32, y: i32, policy: BoundsPolicy) -> Result<Self, ScreenError> {
+        let inside =
+            (0..i32::from(SCREEN_WIDTH)).contains(&x) && (0..i32::from(SCREEN_HEIGHT)).contains(&y);
+        match policy {
+            _ if inside => Ok(Self {
+                x: x as u16,
+                y: y as u16,
+            }),
+            BoundsPolicy::Clamp => Ok(Self::clamped(x, y)),
+            BoundsPolicy::Error => Err(ScreenError::OutOfRange),
+        }
+    }
+
+    // 以左上角为原点、y 向下的坐标（常见于设计稿）
+    pub fn from_top_left(x: u16, y: u16) -> Result<Self, ScreenError> {
+        Self::new(
+            x,
+            (SCREEN_HEIGHT - 1)
+                .checked_sub(y)
+                .ok_or(ScreenError::OutOfRange)?,
+        )
+    }
+
+    pub fn x(self) -> u16 {
+        self.x
+    }
+
+    pub fn y(self) -> u16 {
+        self.y
+    }
+
+    // 相对偏移（dy 向上为正）
+    pub fn offset(self, dx: i32, dy: i32) -> Result<Self, ScreenError> {
+        self.offset_with(dx, dy, BoundsPolicy::Error)
+    }
+
+    pub fn offset_clamped(self, dx: i32, dy: i32) -> Self {
+        Self::clamped(i32::from(self.x) + dx, i32::from(self.y) + dy)
+    }
+
+    pub fn offset_with(self, dx: i32, dy: i32, policy: BoundsPolicy) -> Result<Self, ScreenError> {
+        Self::with_policy(i32::from(self.x) + dx, i32::from(self.y) + dy, policy)
+    }
+}
+
+// 屏幕上的矩形区域（左下角与宽高），构造时保证区域不超出屏幕（右、上边界不含在内，
+// 原点为 (0, 0)、宽 1920 高 1080 即为全屏）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScreenRect {
+    origin: ScreenPoint,
+    width: u16,
+    height: u16,
+}
+
+impl ScreenRect {
+    pub fn new(origin: ScreenPoint, width: u16, height: u16) -> Result<Self, ScreenError> {
+        if u32::from(origin.x) + u32::from(width) > u32::from(SCREEN_WIDTH)
+            || u32::from(origin.y) + u32::from(height) > u32::from(SCREEN_HEIGHT)
+        {
+            return Err(ScreenError::OutOfRange);
+        }
+        Ok(Self {
+            origin,
+            width,
+            height,
+        })
+    }
+
+    pub fn centered(center: ScreenPoint, width: u16, height: u16) -> Result<Self, ScreenError> {
+        let origin = center.offset(-i32::from(width / 2), -i32::from(height / 2))?;
+        Self::new(origin, width, height)
+    }
+
+    pub fn origin(self) -> ScreenPoint {
+        self.origin
+    }
+
+    pub fn width(self) -> u16 {
+        self.width
+    }
+
+    pub fn height(self) -> u16 {
+        self.height
+    }
+
+    pub fn center(self) -> ScreenPoint {
+        self.point(self.width / 2, self.height / 2)
+    }
+
+    // 相对左下角的点，超出区域时截断到区域边缘
+    pub fn point(self, dx: u16, dy: u16) -> ScreenPoint {
+        self.origin
+            .offset_clamped(dx.min(self.width).into(), dy.min(self.height).into())
+    }
+}
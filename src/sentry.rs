@@ -0,0 +1,229 @@
+use deku::{DekuContainerWrite, DekuError};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{EndpointId, RefereeFrame, RefereeFrameCmdData, RobotID, SentryCmd};
+
+// 0x0120 各累计字段的位宽上限
+const ALLOWANCE_MAX: u16 = (1 << 11) - 1;
+const COUNT_MASK: u8 = 0x0F;
+
+// 0x020D 哨兵自主决策信息同步，按位展开
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SentryStatus {
+    // bit 0-10：除远程兑换外，哨兵已成功兑换的发弹量
+    pub exchanged_projectile_allowance: u16,
+    // bit 11-14：哨兵成功远程兑换发弹量的次数
+    pub remote_projectile_exchanges: u8,
+    // bit 15-18：哨兵成功远程兑换血量的次数
+    pub remote_hp_exchanges: u8,
+    // bit 19：当前是否可以确认免费复活
+    pub can_confirm_free_revive: bool,
+    // bit 20：当前是否可以兑换立即复活
+    pub can_exchange_immediate_revive: bool,
+    // bit 21-30：兑换立即复活需要花费的金币数
+    pub immediate_revive_cost: u16,
+    // sentry_info_2 bit 0：是否处于脱战状态
+    pub out_of_combat: bool,
+    // sentry_info_2 bit 1-11：队伍 17mm 允许发弹量的剩余可兑换数
+    pub remaining_exchangeable_projectiles: u16,
+}
+
+impl SentryStatus {
+    pub fn from_raw(sentry_info: u32, sentry_info_2: u16) -> Self {
+        Self {
+            exchanged_projectile_allowance: (sentry_info & 0x7FF) as u16,
+            remote_projectile_exchanges: ((sentry_info >> 11) & 0x0F) as u8,
+            remote_hp_exchanges: ((sentry_info >> 15) & 0x0F) as u8,
+            can_confirm_free_revive: sentry_info & (1 << 19) != 0,
+            can_exchange_immediate_revive: sentry_info & (1 << 20) != 0,
+            immediate_revive_cost: ((sentry_info >> 21) & 0x3FF) as u16,
+            out_of_combat: sentry_info_2 & 1 != 0,
+            remaining_exchangeable_projectiles: (sentry_info_2 >> 1) & 0x7FF,
+        }
+    }
+}
+
+impl RefereeFrameCmdData {
+    // 将 0x020D 解析为按位展开的哨兵信息
+    pub fn as_sentry_status(&self) -> Option<SentryStatus> {
+        match *self {
+            RefereeFrameCmdData::SentryInfo {
+                sentry_info,
+                sentry_info_2,
+            } => Some(SentryStatus::from_raw(sentry_info, sentry_info_2)),
+            _ => None,
+        }
+    }
+}
+
+// 哨兵自主决策会话：记录已发送的 0x0120 累计值，按意图生成下一帧，并依据 0x020D 反馈校正。
+// 远程兑换计数每帧最多 +1，多次请求会排队到后续帧依次发出。
+#[derive(Debug, Clone, PartialEq)]
+pub struct SentryCommander {
+    robot: RobotID,
+    sent: SentryCmd,
+    target_allowance: u16,
+    queued_remote_projectiles: u8,
+    queued_remote_hp: u8,
+    revive: bool,
+    immediate_revive: bool,
+    status: Option<SentryStatus>,
+}
+
+impl SentryCommander {
+    pub fn new(robot: RobotID) -> Self {
+        Self {
+            robot,
+            sent: SentryCmd {
+                confirm_revive: false,
+                confirm_immediate_revive: false,
+                exchange_projectile_allowance: 0,
+                remote_exchange_projectile_count: 0,
+                remote_exchange_hp_count: 0,
+            },
+            target_allowance: 0,
+            queued_remote_projectiles: 0,
+            queued_remote_hp: 0,
+            revive: false,
+            immediate_revive: false,
+            status: None,
+        }
+    }
+
+    // 追加兑换 n 发允许发弹量；累计值超出 11 bit 时拒绝并返回 false
+    pub fn request_projectiles(&mut self, n: u16) -> bool {
+        match self.target_allowance.checked_add(n) {
+            Some(v) if v <= ALLOWANCE_MAX => {
+                self.target_allowance = v;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // 请求一次远程兑换发弹量
+    pub fn request_remote_projectiles(&mut self) {
+        self.queued_remote_projectiles = self.queued_remote_projectiles.saturating_add(1);
+    }
+
+    // 请求一次远程兑换血量
+    pub fn request_remote_hp(&mut self) {
+        self.queued_remote_hp = self.queued_remote_hp.saturating_add(1);
+    }
+
+    // 确认复活；该位为电平语义，保持置位直到 cancel_revive
+    pub fn confirm_revive(&mut self) {
+        self.revive = true;
+    }
+
+    pub fn cancel_revive(&mut self) {
+        self.revive = false;
+    }
+
+    // 确认兑换立即复活；仅随下一帧发送一次
+    pub fn confirm_immediate_revive(&mut self) {
+        self.immediate_revive = true;
+    }
+
+    // 喂入一条命令数据；收到 0x020D 时校正累计基准并返回 true
+    pub fn update(&mut self, data: &RefereeFrameCmdData) -> bool {
+        let Some(status) = data.as_sentry_status() else {
+            return false;
+        };
+        // 服务器已确认的值领先于本地（例如本地重启）时，以服务器为新的基准，保证后续发送仍单调递增
+        if status.exchanged_projectile_allowance > self.sent.exchange_projectile_allowance {
+            self.sent.exchange_projectile_allowance = status.exchanged_projectile_allowance;
+        }
+        self.target_allowance = self
+            .target_allowance
+            .max(self.sent.exchange_projectile_allowance);
+        if count_ahead(
+            status.remote_projectile_exchanges,
+            self.sent.remote_exchange_projectile_count,
+        ) {
+            self.sent.remote_exchange_projectile_count = status.remote_projectile_exchanges;
+        }
+        if count_ahead(
+            status.remote_hp_exchanges,
+            self.sent.remote_exchange_hp_count,
+        ) {
+            self.sent.remote_exchange_hp_count = status.remote_hp_exchanges;
+        }
+        self.status = Some(status);
+        true
+    }
+
+    pub fn status(&self) -> Option<&SentryStatus> {
+        self.status.as_ref()
+    }
+
+    // 最近一次发送的指令
+    pub fn last_sent(&self) -> SentryCmd {
+        self.sent
+    }
+
+    // 已发送但尚未被 0x020D 确认的兑换发弹量
+    pub fn unconfirmed_projectiles(&self) -> u16 {
+        let confirmed = self.status.map_or(0, |s| s.exchanged_projectile_allowance);
+        self.sent
+            .exchange_projectile_allowance
+            .saturating_sub(confirmed)
+    }
+
+    // 下一帧将要发送的指令
+    pub fn next_cmd(&self) -> SentryCmd {
+        SentryCmd {
+            confirm_revive: self.revive,
+            confirm_immediate_revive: self.immediate_revive,
+            exchange_projectile_allowance: self.target_allowance,
+            remote_exchange_projectile_count: step_count(
+                self.sent.remote_exchange_projectile_count,
+                self.queued_remote_projectiles,
+            ),
+            remote_exchange_hp_count: step_count(
+                self.sent.remote_exchange_hp_count,
+                self.queued_remote_hp,
+            ),
+        }
+    }
+
+    // 是否有尚未发出的变化
+    pub fn has_pending(&self) -> bool {
+        self.next_cmd() != self.sent
+    }
+
+    // 生成发往服务器的下一帧 0x0301 / 0x0120，并记为已发送
+    pub fn next_frame(&mut self, seq: u8) -> Result<RefereeFrame, DekuError> {
+        let cmd = self.next_cmd();
+        let frame = RefereeFrame::new(
+            seq,
+            RefereeFrameCmdData::RobotInteractionData {
+                data_cmd_id: 0x0120,
+                sender_id: self.robot.into(),
+                receiver_id: EndpointId::Server,
+                user_data: cmd.to_bytes()?,
+            },
+        )?;
+        self.queued_remote_projectiles = self.queued_remote_projectiles.saturating_sub(1);
+        self.queued_remote_hp = self.queued_remote_hp.saturating_sub(1);
+        self.immediate_revive = false;
+        self.sent = cmd;
+        Ok(frame)
+    }
+}
+
+fn step_count(sent: u8, queued: u8) -> u8 {
+    if queued > 0 {
+        sent.wrapping_add(1) & COUNT_MASK
+    } else {
+        sent
+    }
+}
+
+// 4 bit 回绕计数：a 是否领先于 b（差值落在前半圈内）
+fn count_ahead(a: u8, b: u8) -> bool {
+    let diff = a.wrapping_sub(b) & COUNT_MASK;
+    diff != 0 && diff < 8
+}
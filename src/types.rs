@@ -92,6 +92,7 @@ pub enum EndpointID {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[deku(id_type = "u8", bits = 4)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum GameType {
     #[deku(id = "1")]
     RMUC = 1,
@@ -0,0 +1,220 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use core::time::Duration;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::hud::pack_ops;
+use crate::robot_interaction::MAX_INTERACTION_RATE_HZ;
+use crate::{FigureOperateType, HudElement, HudOp, HudScene, RobotInteractionUserData};
+
+// 图形发送优先级
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UiPriority {
+    Low,
+    #[default]
+    Normal,
+    Critical,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Pending {
+    order: u64,
+    op: HudOp,
+}
+
+// UI 发送调度器：位于 HudScene 与编码之间。
+// 限制 0x0301 发送频率；同名图形的待发送操作合并为一条；按优先级出队；
+// 并穿插慢速轮询的“重新新增”，使重启后的选手端无需操作即可恢复全部图形。
+#[derive(Debug, Clone, PartialEq)]
+pub struct UiScheduler {
+    min_interval: Duration,
+    last_send: Option<Duration>,
+    pending: BTreeMap<[u8; 3], Pending>,
+    next_order: u64,
+    priorities: BTreeMap<[u8; 3], UiPriority>,
+    // 全部操作生效后客户端应持有的图形
+    known: BTreeMap<[u8; 3], HudElement>,
+    resync_interval: Option<Duration>,
+    next_resync: Duration,
+    resync_cursor: Option<[u8; 3]>,
+}
+
+impl UiScheduler {
+    // rate_hz 超过协议上限时按上限处理
+    pub fn new(rate_hz: u32) -> Self {
+        let rate = rate_hz.clamp(1, MAX_INTERACTION_RATE_HZ);
+        Self {
+            min_interval: Duration::from_secs(1) / rate,
+            last_send: None,
+            pending: BTreeMap::new(),
+            next_order: 0,
+            priorities: BTreeMap::new(),
+            known: BTreeMap::new(),
+            resync_interval: None,
+            next_resync: Duration::ZERO,
+            resync_cursor: None,
+        }
+    }
+
+    // 每隔 interval 让出一个发送时隙用于重新新增已有图形
+    pub fn with_resync(mut self, interval: Duration) -> Self {
+        self.resync_interval = Some(interval);
+        self
+    }
+
+    pub fn set_priority(&mut self, name: [u8; 3], priority: UiPriority) {
+        self.priorities.insert(name, priority);
+    }
+
+    pub fn priority(&self, name: &[u8; 3]) -> UiPriority {
+        self.priorities.get(name).copied().unwrap_or_default()
+    }
+
+    // 取出场景差异并入队
+    pub fn sync(&mut self, scene: &mut HudScene) {
+        for op in scene.diff() {
+            scene.commit(&op);
+            self.push(op);
+        }
+    }
+
+    // 入队一条操作，与同名的未发送操作合并
+    pub fn push(&mut self, op: HudOp) {
+        let name = op.element.name();
+        match op.operate_type {
+            FigureOperateType::Delete => {
+                self.known.remove(&name);
+            }
+            FigureOperateType::Add | FigureOperateType::Modify => {
+                self.known.insert(name, op.element);
+            }
+            FigureOperateType::None => return,
+        }
+        let prev = self.pending.remove(&name);
+        let merged = match (prev.map(|p| p.op.operate_type), op.operate_type) {
+            // 新增尚未发出即被删除：客户端从未见过，直接丢弃
+            (Some(FigureOperateType::Add), FigureOperateType::Delete) => return,
+            (Some(FigureOperateType::Add), _) => HudOp {
+                operate_type: FigureOperateType::Add,
+                element: op.element,
+            },
+            // 删除尚未发出又重新出现：客户端仍持有旧图形，改为修改
+            (
+                Some(FigureOperateType::Delete),
+                FigureOperateType::Add | FigureOperateType::Modify,
+            ) => HudOp {
+                operate_type: FigureOperateType::Modify,
+                element: op.element,
+            },
+            _ => op,
+        };
+        let order = prev.map_or_else(
+            || {
+                self.next_order += 1;
+                self.next_order
+            },
+            |p| p.order,
+        );
+        self.pending.insert(name, Pending { order, op: merged });
+    }
+
+    // 选手端已知重启：所有已知图形以新增的形式重新排队
+    pub fn client_reset(&mut self) {
+        self.pending.clear();
+        let elements: Vec<HudElement> = self.known.values().copied().collect();
+        for element in elements {
+            self.push(HudOp {
+                operate_type: FigureOperateType::Add,
+                element,
+            });
+        }
+    }
+
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+
+    // 到达发送时隙时返回下一条待发送的子内容
+    pub fn poll(&mut self, now: Duration) -> Option<RobotInteractionUserData> {
+        if self
+            .last_send
+            .is_some_and(|t| now.saturating_sub(t) < self.min_interval)
+        {
+            return None;
+        }
+        let has_critical = self
+            .pending
+            .keys()
+            .any(|name| self.priority(name) == UiPriority::Critical);
+        let resync_due = self.resync_interval.is_some() && now >= self.next_resync;
+        let data = if resync_due && !has_critical {
+            self.next_resync = now + self.resync_interval.unwrap_or_default();
+            self.next_resync_batch()
+                .or_else(|| self.next_pending_batch())
+        } else {
+            self.next_pending_batch()
+        }?;
+        self.last_send = Some(now);
+        Some(data)
+    }
+
+    fn next_pending_batch(&mut self) -> Option<RobotInteractionUserData> {
+        let mut queue: Vec<([u8; 3], Pending)> =
+            self.pending.iter().map(|(n, p)| (*n, *p)).collect();
+        queue.sort_by_key(|(name, p)| (Reverse(self.priority(name)), p.order));
+        let (first_name, first) = *queue.first()?;
+        if !first.op.is_batchable() {
+            self.pending.remove(&first_name);
+            return Some(first.op.to_user_data());
+        }
+        let batch: Vec<([u8; 3], HudOp)> = queue
+            .iter()
+            .filter(|(_, p)| p.op.is_batchable())
+            .map(|(n, p)| (*n, p.op))
+            .take(7)
+            .collect();
+        let batch = &batch[..batch_size(batch.len())];
+        for (name, _) in batch {
+            self.pending.remove(name);
+        }
+        let ops: Vec<HudOp> = batch.iter().map(|(_, op)| *op).collect();
+        pack_ops(&ops).into_iter().next()
+    }
+
+    fn next_resync_batch(&mut self) -> Option<RobotInteractionUserData> {
+        // 从游标之后开始轮询，跳过仍有待发送操作的图形
+        let (after, before): (Vec<_>, Vec<_>) = self
+            .known
+            .iter()
+            .filter(|(name, _)| !self.pending.contains_key(*name))
+            .map(|(name, e)| (*name, *e))
+            .partition(|(name, _)| Some(*name) > self.resync_cursor);
+        let candidates: Vec<HudElement> = after.into_iter().chain(before).map(|(_, e)| e).collect();
+        let first = *candidates.first()?;
+        let add = |element: HudElement| HudOp {
+            operate_type: FigureOperateType::Add,
+            element,
+        };
+        if let HudElement::Text(_) = first {
+            self.resync_cursor = Some(first.name());
+            return Some(add(first).to_user_data());
+        }
+        let run: Vec<HudOp> = candidates
+            .iter()
+            .take_while(|e| matches!(e, HudElement::Figure(_)))
+            .take(7)
+            .map(|e| add(*e))
+            .collect();
+        let run = &run[..batch_size(run.len())];
+        self.resync_cursor = run.last().map(|op| op.element.name());
+        pack_ops(run).into_iter().next()
+    }
+}
+
+// 不超过 n 的最大单条批量（7 / 5 / 2 / 1）
+fn batch_size(n: usize) -> usize {
+    [7, 5, 2, 1].into_iter().find(|&s| s <= n).unwrap_or(0)
+}
@@ -0,0 +1,229 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    EndpointId, Figure, FigureError, FigureOperateType, FigureType, HudElement, InteractionFigure,
+    LayerDeleteType, RefereeFrameCmdData, RobotInteractionUserData, TextError, TextFigure,
+};
+
+// 规则未给出选手端图形总数上限，默认值可用 with_figure_limit 调整
+pub const DEFAULT_FIGURE_LIMIT: usize = 300;
+
+// 选手端图形协议的误用
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ClientViolation {
+    // 发送方不是本选手端对应的机器人
+    WrongSender(EndpointId),
+    // 图形子内容无法解析
+    Malformed(u16),
+    // 图层超出 0-9
+    InvalidLayer(u8),
+    NoneOperation([u8; 3]),
+    NoneLayerDelete,
+    // 新增已存在且内容不同的图形
+    DuplicateAdd([u8; 3]),
+    // 新增与已有图形完全相同的图形，不影响显示（如 UiScheduler 的重新同步）
+    RedundantAdd([u8; 3]),
+    ModifyMissing([u8; 3]),
+    DeleteMissing([u8; 3]),
+    // 已达图形数量上限，新增被丢弃
+    LimitExceeded([u8; 3]),
+    // 通过 0x0101~0x0104 新增 / 修改字符图形（内容只能随 0x0110 发送）
+    TextWithoutContent([u8; 3]),
+    // 0x0110 的图形类型不是字符或内容不合法
+    InvalidText([u8; 3], TextError),
+    // 新增 / 修改的图形起点或终点在屏幕外
+    InvalidFigure([u8; 3], FigureError),
+}
+
+impl ClientViolation {
+    // RedundantAdd 只是冗余发送，其余误用会使选手端显示与发送方预期不一致
+    pub fn is_error(&self) -> bool {
+        !matches!(self, Self::RedundantAdd(_))
+    }
+}
+
+// 虚拟选手端：消费发往某个选手端的 0x0301 图形帧，维护选手端应显示的图形并记录协议误用，
+// 便于在无裁判系统时端到端测试 HUD 代码。
+#[derive(Debug, Clone, PartialEq)]
+pub struct VirtualClient {
+    client: EndpointId,
+    limit: usize,
+    figures: BTreeMap<[u8; 3], HudElement>,
+}
+
+impl VirtualClient {
+    pub fn new(client: EndpointId) -> Self {
+        Self {
+            client,
+            limit: DEFAULT_FIGURE_LIMIT,
+            figures: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_figure_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    pub fn client(&self) -> EndpointId {
+        self.client
+    }
+
+    // 处理一条裁判系统数据：非 0x0301、非发往本选手端或非图形子内容时忽略
+    pub fn consume(&mut self, cmd_data: &RefereeFrameCmdData) -> Vec<ClientViolation> {
+        let RefereeFrameCmdData::RobotInteractionData {
+            data_cmd_id,
+            sender_id,
+            receiver_id,
+            ..
+        } = cmd_data
+        else {
+            return Vec::new();
+        };
+        if *receiver_id != self.client || !(0x0100..=0x0110).contains(data_cmd_id) {
+            return Vec::new();
+        }
+        if *sender_id as u16 + 0x0100 != self.client as u16 {
+            return alloc::vec![ClientViolation::WrongSender(*sender_id)];
+        }
+        match cmd_data.as_robot_interaction() {
+            Some(data) => self.apply(&data),
+            None => alloc::vec![ClientViolation::Malformed(*data_cmd_id)],
+        }
+    }
+
+    // 应用一条图形子内容，返回其中的协议误用；合法的部分照常生效
+    pub fn apply(&mut self, data: &RobotInteractionUserData) -> Vec<ClientViolation> {
+        let mut violations = Vec::new();
+        let figures: &[InteractionFigure] = match data {
+            RobotInteractionUserData::LayerDelete(d) => {
+                match d.delete_type {
+                    LayerDeleteType::None => violations.push(ClientViolation::NoneLayerDelete),
+                    LayerDeleteType::DeleteAll => self.figures.clear(),
+                    LayerDeleteType::DeleteLayer if d.layer > 9 => {
+                        violations.push(ClientViolation::InvalidLayer(d.layer));
+                    }
+                    LayerDeleteType::DeleteLayer => {
+                        self.figures.retain(|_, e| e.layer() != d.layer);
+                    }
+                }
+                return violations;
+            }
+            RobotInteractionUserData::Figure(f) => core::slice::from_ref(f),
+            RobotInteractionUserData::Figures2(f) => f,
+            RobotInteractionUserData::Figures5(f) => f,
+            RobotInteractionUserData::Figures7(f) => f,
+            RobotInteractionUserData::CustomCharacter { figure, data } => {
+                let element = match TextFigure::from_custom_character(figure, data) {
+                    Ok(text) => Some(HudElement::Text(text)),
+                    Err(_) if figure.operate_type == FigureOperateType::Delete => None,
+                    Err(err) => {
+                        violations.push(ClientViolation::InvalidText(figure.figure_name, err));
+                        return violations;
+                    }
+                };
+                self.operate(figure, element, &mut violations);
+                return violations;
+            }
+            _ => &[],
+        };
+        for figure in figures {
+            let element = if figure.figure_type == FigureType::Char {
+                if matches!(
+                    figure.operate_type,
+                    FigureOperateType::Add | FigureOperateType::Modify
+                ) {
+                    violations.push(ClientViolation::TextWithoutContent(figure.figure_name));
+                    continue;
+                }
+                None
+            } else {
+                match Figure::try_from(figure) {
+                    Ok(fig) => Some(HudElement::Figure(fig)),
+                    Err(_) if figure.operate_type == FigureOperateType::Delete => None,
+                    Err(err) => {
+                        violations.push(ClientViolation::InvalidFigure(figure.figure_name, err));
+                        continue;
+                    }
+                }
+            };
+            self.operate(figure, element, &mut violations);
+        }
+        violations
+    }
+
+    // 选手端重启：清空全部图形
+    pub fn reset(&mut self) {
+        self.figures.clear();
+    }
+
+    pub fn get(&self, name: &[u8; 3]) -> Option<&HudElement> {
+        self.figures.get(name)
+    }
+
+    pub fn figures(&self) -> impl Iterator<Item = &HudElement> {
+        self.figures.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.figures.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.figures.is_empty()
+    }
+
+    pub fn layer_len(&self, layer: u8) -> usize {
+        self.figures.values().filter(|e| e.layer() == layer).count()
+    }
+
+    fn operate(
+        &mut self,
+        figure: &InteractionFigure,
+        element: Option<HudElement>,
+        violations: &mut Vec<ClientViolation>,
+    ) {
+        let name = figure.figure_name;
+        let current = self.figures.get(&name).copied();
+        if matches!(
+            figure.operate_type,
+            FigureOperateType::Add | FigureOperateType::Modify
+        ) && figure.layer > 9
+        {
+            violations.push(ClientViolation::InvalidLayer(figure.layer));
+            return;
+        }
+        match (figure.operate_type, element) {
+            (FigureOperateType::None, _) => violations.push(ClientViolation::NoneOperation(name)),
+            (FigureOperateType::Delete, _) => {
+                if self.figures.remove(&name).is_none() {
+                    violations.push(ClientViolation::DeleteMissing(name));
+                }
+            }
+            (FigureOperateType::Add, Some(element)) => {
+                if current == Some(element) {
+                    violations.push(ClientViolation::RedundantAdd(name));
+                    return;
+                } else if current.is_some() {
+                    violations.push(ClientViolation::DuplicateAdd(name));
+                } else if self.figures.len() >= self.limit {
+                    violations.push(ClientViolation::LimitExceeded(name));
+                    return;
+                }
+                self.figures.insert(name, element);
+            }
+            (FigureOperateType::Modify, Some(element)) => {
+                if current.is_some() {
+                    self.figures.insert(name, element);
+                } else {
+                    violations.push(ClientViolation::ModifyMissing(name));
+                }
+            }
+            (_, None) => {}
+        }
+    }
+}
@@ -0,0 +1,514 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{
+    BulletType, Figure, FigureColor, HudElement, HudScene, RefereeFrameCmdData, ScreenRect,
+    ShooterNumber, TextFigure,
+};
+
+// HUD 控件：由裁判系统数据驱动，在给定图层与屏幕区域内生成图形。
+// 每个控件使用 2 字节名称前缀，第三字节为 '0'-'9' 的序号，因此一个控件最多 10 个图形。
+pub trait Widget {
+    // 用一条裁判系统数据更新状态；数据与本控件相关时返回 true
+    fn update(&mut self, data: &RefereeFrameCmdData) -> bool {
+        let _ = data;
+        false
+    }
+
+    // 当前状态对应的全部图形；图形集合固定，只有内容随状态变化
+    fn elements(&self) -> Vec<HudElement>;
+
+    // 写入场景，替换同名元素
+    fn place(&self, scene: &mut HudScene) {
+        for element in self.elements() {
+            scene.set(element);
+        }
+    }
+}
+
+// 控件的名称前缀、图层与区域
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Placement {
+    prefix: [u8; 2],
+    layer: u8,
+    area: ScreenRect,
+}
+
+impl Placement {
+    fn name(&self, index: u8) -> [u8; 3] {
+        [self.prefix[0], self.prefix[1], b'0' + index]
+    }
+
+    // 单行文字：字体大小取区域高度，起点为区域左上角
+    fn text(&self, index: u8, color: FigureColor, text: &str) -> HudElement {
+        let font_size = self.area.height();
+        let pos = self.area.point(0, font_size);
+        TextFigure::new_lossy(
+            self.name(index),
+            self.layer,
+            color,
+            font_size,
+            (font_size / 10).max(1),
+            pos,
+            text,
+        )
+        .into()
+    }
+
+    // 进度条：边框与按比例伸缩的粗线填充
+    fn bar(&self, fraction: f32, color: FigureColor) -> Vec<HudElement> {
+        const PAD: u16 = 2;
+        let area = self.area;
+        let frame = Figure::rect(
+            self.name(0),
+            self.layer,
+            FigureColor::White,
+            1,
+            area.origin(),
+            area.point(area.width(), area.height()),
+        );
+        let inner = area.width().saturating_sub(2 * PAD);
+        let filled = (f32::from(inner) * fraction.clamp(0.0, 1.0)) as u16;
+        let mid = area.height() / 2;
+        let thickness = if filled == 0 {
+            0
+        } else {
+            area.height().saturating_sub(2 * PAD)
+        };
+        let fill = Figure::line(
+            self.name(1),
+            self.layer,
+            color,
+            thickness,
+            area.point(PAD, mid),
+            area.point(PAD + filled, mid),
+        );
+        alloc::vec![frame.into(), fill.into()]
+    }
+}
+
+// 十字准星与弹道下坠刻度
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crosshair {
+    placement: Placement,
+    color: FigureColor,
+    width: u16,
+    drop_marks: Vec<u16>,
+}
+
+impl Crosshair {
+    pub const MAX_DROP_MARKS: usize = 8;
+
+    // 十字中心为区域中心
+    pub fn new(prefix: [u8; 2], layer: u8, area: ScreenRect) -> Self {
+        Self {
+            placement: Placement {
+                prefix,
+                layer,
+                area,
+            },
+            color: FigureColor::Green,
+            width: 2,
+            drop_marks: Vec::new(),
+        }
+    }
+
+    pub fn with_color(mut self, color: FigureColor) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn with_width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    // 各距离上弹丸相对准星中心的下坠像素，由近到远；超过 MAX_DROP_MARKS 的部分忽略
+    pub fn with_drop_marks(mut self, drops: &[u16]) -> Self {
+        self.drop_marks = drops.iter().copied().take(Self::MAX_DROP_MARKS).collect();
+        self
+    }
+
+    pub fn drop_marks(&self) -> &[u16] {
+        &self.drop_marks
+    }
+}
+
+impl Widget for Crosshair {
+    fn elements(&self) -> Vec<HudElement> {
+        let p = &self.placement;
+        let area = p.area;
+        let (cx, cy) = (area.width() / 2, area.height() / 2);
+        let line = |index: u8, from, to| {
+            HudElement::from(Figure::line(
+                p.name(index),
+                p.layer,
+                self.color,
+                self.width,
+                from,
+                to,
+            ))
+        };
+        let mut elements = alloc::vec![
+            line(0, area.point(0, cy), area.point(area.width(), cy)),
+            line(1, area.point(cx, 0), area.point(cx, area.height())),
+        ];
+        // 刻度随距离变短，便于区分
+        let half = area.width() / 4;
+        for (i, &drop) in self.drop_marks.iter().enumerate() {
+            let y = cy.saturating_sub(drop);
+            let half = half * (Self::MAX_DROP_MARKS - i) as u16 / Self::MAX_DROP_MARKS as u16;
+            elements.push(line(
+                2 + i as u8,
+                area.point(cx - half, y),
+                area.point(cx + half, y),
+            ));
+        }
+        elements
+    }
+}
+
+// 枪管热量条：热量来自 0x0202，上限来自 0x0201
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeatBar {
+    placement: Placement,
+    shooter: ShooterNumber,
+    heat: u16,
+    limit: u16,
+}
+
+impl HeatBar {
+    pub fn new(prefix: [u8; 2], layer: u8, area: ScreenRect, shooter: ShooterNumber) -> Self {
+        Self {
+            placement: Placement {
+                prefix,
+                layer,
+                area,
+            },
+            shooter,
+            heat: 0,
+            limit: 0,
+        }
+    }
+
+    pub fn heat(&self) -> u16 {
+        self.heat
+    }
+
+    pub fn limit(&self) -> u16 {
+        self.limit
+    }
+
+    // 已用热量比例；上限未知时为 0
+    pub fn fraction(&self) -> f32 {
+        if self.limit == 0 {
+            0.0
+        } else {
+            f32::from(self.heat) / f32::from(self.limit)
+        }
+    }
+}
+
+impl Widget for HeatBar {
+    fn update(&mut self, data: &RefereeFrameCmdData) -> bool {
+        match data {
+            RefereeFrameCmdData::PowerHeatData {
+                shooter_17mm_1_barrel_heat,
+                shooter_17mm_2_barrel_heat,
+                shooter_42mm_barrel_heat,
+                ..
+            } => {
+                self.heat = match self.shooter {
+                    ShooterNumber::Shooter17mm1 => *shooter_17mm_1_barrel_heat,
+                    ShooterNumber::Shooter17mm2 => *shooter_17mm_2_barrel_heat,
+                    ShooterNumber::Shooter42mm => *shooter_42mm_barrel_heat,
+                };
+                true
+            }
+            RefereeFrameCmdData::RobotStatus {
+                shooter_barrel_heat_limit,
+                ..
+            } => {
+                self.limit = *shooter_barrel_heat_limit;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // 接近上限时由绿变橙再变紫红
+    fn elements(&self) -> Vec<HudElement> {
+        let fraction = self.fraction();
+        let color = if fraction >= 0.85 {
+            FigureColor::Magenta
+        } else if fraction >= 0.6 {
+            FigureColor::Orange
+        } else {
+            FigureColor::Green
+        };
+        self.placement.bar(fraction, color)
+    }
+}
+
+// 缓冲能量条（0x0202 buffer_energy）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferEnergyBar {
+    placement: Placement,
+    energy: u16,
+    max: u16,
+}
+
+impl BufferEnergyBar {
+    // 缓冲能量上限默认 60 J
+    pub const DEFAULT_MAX: u16 = 60;
+
+    pub fn new(prefix: [u8; 2], layer: u8, area: ScreenRect) -> Self {
+        Self {
+            placement: Placement {
+                prefix,
+                layer,
+                area,
+            },
+            energy: 0,
+            max: Self::DEFAULT_MAX,
+        }
+    }
+
+    pub fn with_max(mut self, max: u16) -> Self {
+        self.max = max;
+        self
+    }
+
+    pub fn energy(&self) -> u16 {
+        self.energy
+    }
+}
+
+impl Widget for BufferEnergyBar {
+    fn update(&mut self, data: &RefereeFrameCmdData) -> bool {
+        if let RefereeFrameCmdData::PowerHeatData { buffer_energy, .. } = data {
+            self.energy = *buffer_energy;
+            return true;
+        }
+        false
+    }
+
+    // 低于 30% 时变橙，提示即将超功率扣血
+    fn elements(&self) -> Vec<HudElement> {
+        let fraction = f32::from(self.energy) / f32::from(self.max.max(1));
+        let color = if fraction < 0.3 {
+            FigureColor::Orange
+        } else {
+            FigureColor::Cyan
+        };
+        self.placement.bar(fraction, color)
+    }
+}
+
+// 允许发弹量计数（0x0208）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmmoCounter {
+    placement: Placement,
+    bullet: BulletType,
+    allowance: u16,
+    low: u16,
+}
+
+impl AmmoCounter {
+    pub fn new(prefix: [u8; 2], layer: u8, area: ScreenRect, bullet: BulletType) -> Self {
+        Self {
+            placement: Placement {
+                prefix,
+                layer,
+                area,
+            },
+            bullet,
+            allowance: 0,
+            low: 0,
+        }
+    }
+
+    // 余量不高于 low 时以橙色显示
+    pub fn with_low_threshold(mut self, low: u16) -> Self {
+        self.low = low;
+        self
+    }
+
+    pub fn allowance(&self) -> u16 {
+        self.allowance
+    }
+}
+
+impl Widget for AmmoCounter {
+    fn update(&mut self, data: &RefereeFrameCmdData) -> bool {
+        if let RefereeFrameCmdData::ProjectileAllowance {
+            projectile_allowance_17mm,
+            projectile_allowance_42mm,
+            ..
+        } = data
+        {
+            self.allowance = match self.bullet {
+                BulletType::Bullet17mm => *projectile_allowance_17mm,
+                BulletType::Bullet42mm => *projectile_allowance_42mm,
+            };
+            return true;
+        }
+        false
+    }
+
+    fn elements(&self) -> Vec<HudElement> {
+        let p = &self.placement;
+        let color = if self.allowance <= self.low {
+            FigureColor::Orange
+        } else {
+            FigureColor::White
+        };
+        let font_size = p.area.height();
+        let figure = Figure::int(
+            p.name(0),
+            p.layer,
+            color,
+            font_size,
+            (font_size / 10).max(1),
+            p.area.point(0, font_size),
+            self.allowance.into(),
+        );
+        alloc::vec![figure.into()]
+    }
+}
+
+// 增益指示（0x0204）：单行列出生效中的增益，超出字符图形长度的部分省略
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuffIndicator {
+    placement: Placement,
+    recovery: u8,
+    cooling: u8,
+    defense: u8,
+    vulnerability: u8,
+    attack: u16,
+}
+
+impl BuffIndicator {
+    pub fn new(prefix: [u8; 2], layer: u8, area: ScreenRect) -> Self {
+        Self {
+            placement: Placement {
+                prefix,
+                layer,
+                area,
+            },
+            recovery: 0,
+            cooling: 0,
+            defense: 0,
+            vulnerability: 0,
+            attack: 0,
+        }
+    }
+
+    // 例如 "ATK+50% DEF+25% CD+5"；无增益时为空
+    pub fn text(&self) -> String {
+        let tokens = [
+            (self.attack > 0).then(|| format!("ATK+{}%", self.attack)),
+            (self.defense > 0).then(|| format!("DEF+{}%", self.defense)),
+            (self.vulnerability > 0).then(|| format!("DEF-{}%", self.vulnerability)),
+            (self.cooling > 0).then(|| format!("CD+{}", self.cooling)),
+            (self.recovery > 0).then(|| format!("HP+{}%", self.recovery)),
+        ];
+        let mut text = String::new();
+        for token in tokens.into_iter().flatten() {
+            let sep = usize::from(!text.is_empty());
+            if text.len() + sep + token.len() > TextFigure::MAX_LEN {
+                break;
+            }
+            if sep == 1 {
+                text.push(' ');
+            }
+            text.push_str(&token);
+        }
+        text
+    }
+}
+
+impl Widget for BuffIndicator {
+    fn update(&mut self, data: &RefereeFrameCmdData) -> bool {
+        if let RefereeFrameCmdData::Buff {
+            recovery_buff,
+            cooling_buff,
+            defense_buff,
+            vulnerability_buff,
+            attack_buff,
+            ..
+        } = data
+        {
+            self.recovery = *recovery_buff;
+            self.cooling = *cooling_buff;
+            self.defense = *defense_buff;
+            self.vulnerability = *vulnerability_buff;
+            self.attack = *attack_buff;
+            return true;
+        }
+        false
+    }
+
+    fn elements(&self) -> Vec<HudElement> {
+        alloc::vec![self.placement.text(0, FigureColor::Yellow, &self.text())]
+    }
+}
+
+// 阶段剩余时间（0x0001 stage_remain_time），显示为 M:SS
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StageTimer {
+    placement: Placement,
+    remain: u16,
+    warn: u16,
+}
+
+impl StageTimer {
+    pub fn new(prefix: [u8; 2], layer: u8, area: ScreenRect) -> Self {
+        Self {
+            placement: Placement {
+                prefix,
+                layer,
+                area,
+            },
+            remain: 0,
+            warn: 30,
+        }
+    }
+
+    // 剩余时间不超过 warn 秒时以橙色显示，默认 30 秒
+    pub fn with_warning(mut self, warn: u16) -> Self {
+        self.warn = warn;
+        self
+    }
+
+    pub fn remain(&self) -> u16 {
+        self.remain
+    }
+
+    pub fn text(&self) -> String {
+        format!("{}:{:02}", self.remain / 60, self.remain % 60)
+    }
+}
+
+impl Widget for StageTimer {
+    fn update(&mut self, data: &RefereeFrameCmdData) -> bool {
+        if let RefereeFrameCmdData::GameStatus {
+            stage_remain_time, ..
+        } = data
+        {
+            self.remain = *stage_remain_time;
+            return true;
+        }
+        false
+    }
+
+    fn elements(&self) -> Vec<HudElement> {
+        let color = if self.remain <= self.warn {
+            FigureColor::Orange
+        } else {
+            FigureColor::White
+        };
+        alloc::vec![self.placement.text(0, color, &self.text())]
+    }
+}
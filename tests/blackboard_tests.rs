@@ -0,0 +1,148 @@
+use core::time::Duration;
+
+use rm_referee_protocol::*;
+
+mod common;
+use common::ms;
+
+const TARGET: u8 = 1;
+const HERO_POSE: u8 = 2;
+const SENTRY_INTENT: u8 = 3;
+
+const ROBOTS: [EndpointId; 3] = [
+    EndpointId::RedHero,
+    EndpointId::RedEngineer,
+    EndpointId::RedSentry,
+];
+
+fn board(local: EndpointId) -> Blackboard {
+    board_in_session(local, 0)
+}
+
+fn board_in_session(local: EndpointId, session: u8) -> Blackboard {
+    let mut board = Blackboard::new(local, session, 30)
+        .with_beacon(Duration::from_secs(1))
+        .with_key(TARGET, EndpointId::RedSentry)
+        .with_key(HERO_POSE, EndpointId::RedHero)
+        .with_key(SENTRY_INTENT, EndpointId::RedSentry);
+    for peer in ROBOTS {
+        board = board.with_peer(peer);
+    }
+    board
+}
+
+// 以 10ms 步长运行所有机器人，按 receiver_id 路由帧；drop(n) 为 true 时丢弃第 n 帧
+fn run(boards: &mut [Blackboard], from: u64, to: u64, mut drop: impl FnMut(usize) -> bool) {
+    let mut n = 0;
+    for t in (from..to).step_by(10) {
+        for i in 0..boards.len() {
+            let Some(frame) = boards[i].poll(ms(t)) else {
+                continue;
+            };
+            n += 1;
+            if drop(n) {
+                continue;
+            }
+            let RefereeFrameCmdData::RobotInteractionData { receiver_id, .. } = &frame else {
+                unreachable!();
+            };
+            if let Some(peer) = boards.iter_mut().find(|b| b.local() == *receiver_id) {
+                peer.receive(&frame);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_blackboard_deltas_propagate_to_all_peers() {
+    let mut boards: Vec<Blackboard> = ROBOTS.into_iter().map(board).collect();
+    assert_eq!(boards[2].set(TARGET, &[3]), Ok(true));
+    assert_eq!(boards[2].set(TARGET, &[3]), Ok(false));
+    assert_eq!(boards[0].set(HERO_POSE, &[10, 20]), Ok(true));
+    run(&mut boards, 0, 200, |_| false);
+    for b in &boards {
+        assert_eq!(b.get(TARGET), Some(&[3][..]));
+        assert_eq!(b.get(HERO_POSE), Some(&[10, 20][..]));
+    }
+}
+
+#[test]
+fn test_blackboard_only_owner_may_write() {
+    let mut hero = board(EndpointId::RedHero);
+    assert_eq!(
+        hero.set(TARGET, &[1]),
+        Err(BlackboardError::NotOwner(TARGET))
+    );
+    assert_eq!(hero.set(9, &[1]), Err(BlackboardError::UnknownKey(9)));
+    assert_eq!(
+        hero.set(HERO_POSE, &[0; 33]),
+        Err(BlackboardError::TooLong(33))
+    );
+    assert_eq!(
+        Blackboard::new(EndpointId::RedHero, 0, 30).with_data_cmd_id(0x0305),
+        Err(MessageError::IdOutOfRange(0x0305))
+    );
+
+    // 工程伪造哨兵拥有的键
+    let forged = RefereeFrameCmdData::RobotInteractionData {
+        data_cmd_id: BLACKBOARD_DATA_CMD_ID,
+        sender_id: EndpointId::RedEngineer,
+        receiver_id: EndpointId::RedHero,
+        user_data: vec![0, 0, TARGET, 0, 0, 1, 7],
+    };
+    assert!(hero.receive(&forged).is_empty());
+    assert_eq!(hero.get(TARGET), None);
+}
+
+#[test]
+fn test_blackboard_beacons_repair_lost_deltas() {
+    let mut boards: Vec<Blackboard> = ROBOTS.into_iter().map(board).collect();
+    boards[2].set(SENTRY_INTENT, &[1]).unwrap();
+    // 第一秒内全部丢帧
+    run(&mut boards, 0, 900, |_| true);
+    assert_eq!(boards[0].get(SENTRY_INTENT), None);
+    run(&mut boards, 900, 1_200, |_| false);
+    assert_eq!(boards[0].get(SENTRY_INTENT), Some(&[1][..]));
+    assert_eq!(boards[1].get(SENTRY_INTENT), Some(&[1][..]));
+}
+
+#[test]
+fn test_blackboard_restarted_owner_is_accepted_through_beacon() {
+    let mut boards: Vec<Blackboard> = ROBOTS.into_iter().map(board).collect();
+    for value in 0..4 {
+        boards[2].set(TARGET, &[value]).unwrap();
+        run(
+            &mut boards,
+            u64::from(value) * 100,
+            u64::from(value) * 100 + 100,
+            |_| false,
+        );
+    }
+    assert_eq!(boards[0].version(TARGET), Some(3));
+
+    // 哨兵重启后版本号从 0 开始，新的会话号使各对端接受新值
+    boards[2] = board_in_session(EndpointId::RedSentry, 1);
+    boards[2].set(TARGET, &[9]).unwrap();
+    run(&mut boards, 400, 500, |_| false);
+    assert_eq!(boards[0].get(TARGET), Some(&[9][..]));
+    assert_eq!(boards[1].get(TARGET), Some(&[9][..]));
+    assert_eq!(boards[0].version(TARGET), Some(0));
+}
+
+#[test]
+fn test_blackboard_delayed_beacon_does_not_roll_back() {
+    let mut sentry = Blackboard::new(EndpointId::RedSentry, 0, 30)
+        .with_beacon(Duration::from_secs(1))
+        .with_key(TARGET, EndpointId::RedSentry)
+        .with_peer(EndpointId::RedHero);
+    let mut hero = board(EndpointId::RedHero);
+    sentry.set(TARGET, &[1]).unwrap();
+    // 启动时的信标延迟到增量之后到达
+    let beacon = sentry.poll(ms(0)).unwrap();
+    sentry.set(TARGET, &[2]).unwrap();
+    let delta = sentry.poll(ms(100)).unwrap();
+    assert_eq!(hero.receive(&delta), [TARGET]);
+    assert!(hero.receive(&beacon).is_empty());
+    assert_eq!(hero.get(TARGET), Some(&[2][..]));
+    assert_eq!(hero.version(TARGET), Some(1));
+}
@@ -0,0 +1,19 @@
+// 集成测试共用的辅助函数；各测试文件只用到其中一部分
+#![allow(dead_code)]
+
+use core::time::Duration;
+
+use rm_referee_protocol::*;
+
+pub fn ms(v: u64) -> Duration {
+    Duration::from_millis(v)
+}
+
+pub fn pt(x: u16, y: u16) -> ScreenPoint {
+    ScreenPoint::new(x, y).unwrap()
+}
+
+// 图层 1 上 x 处的竖线
+pub fn line(name: &[u8; 3], x: u16) -> Figure {
+    Figure::line(*name, 1, FigureColor::Green, 2, pt(x, 100), pt(x, 200))
+}
@@ -0,0 +1,69 @@
+use deku::prelude::*;
+use rm_referee_protocol::*;
+
+#[test]
+fn test_custom_info_encodes_utf16_and_null_pads() {
+    let info =
+        CustomInfo::text(EndpointId::RedSentry, EndpointId::RedHeroClient, "前哨站A").unwrap();
+    let cmd = info.to_cmd_data();
+    let RefereeFrameCmdData::CustomInfo { data, .. } = &cmd else {
+        panic!("unexpected {cmd:?}");
+    };
+    assert_eq!(&data[..8], &[0x4D, 0x52, 0xE8, 0x54, 0xD9, 0x7A, b'A', 0]);
+    assert!(data[8..].iter().all(|b| *b == 0));
+
+    let bytes = cmd.to_bytes().unwrap();
+    let parsed = RefereeFrameCmdData::try_from(bytes.as_slice()).unwrap();
+    assert_eq!(CustomInfo::from_cmd_data(&parsed), Ok(info));
+}
+
+#[test]
+fn test_custom_info_truncates_on_character_boundaries() {
+    let long = CustomInfo::text(
+        EndpointId::BlueHero,
+        EndpointId::BlueAerialClient,
+        "abcdefghijklmnopqrstuvwxyz",
+    )
+    .unwrap();
+    assert_eq!(long.as_str(), "abcdefghijklmno");
+
+    // 😀 占两个码元，第 15 个码元处放不下时整体丢弃
+    let emoji = CustomInfo::text(
+        EndpointId::BlueHero,
+        EndpointId::BlueAerialClient,
+        "abcdefghijklmn😀",
+    )
+    .unwrap();
+    assert_eq!(emoji.as_str(), "abcdefghijklmn");
+    let round_trip = CustomInfo::from_cmd_data(&emoji.to_cmd_data()).unwrap();
+    assert_eq!(round_trip.as_str(), "abcdefghijklmn");
+}
+
+#[test]
+fn test_custom_info_validates_endpoints_and_encoding() {
+    assert_eq!(
+        CustomInfo::text(EndpointId::RedHero, EndpointId::BlueHeroClient, "x"),
+        Err(CustomInfoError::CrossTeam)
+    );
+    assert_eq!(
+        CustomInfo::text(EndpointId::RedHeroClient, EndpointId::RedHeroClient, "x"),
+        Err(CustomInfoError::SenderNotRobot(EndpointId::RedHeroClient))
+    );
+    assert_eq!(
+        CustomInfo::text(EndpointId::RedHero, EndpointId::RedSentry, "x"),
+        Err(CustomInfoError::ReceiverNotClient(EndpointId::RedSentry))
+    );
+
+    // 孤立的高代理项
+    let mut data = [0; CUSTOM_INFO_LEN];
+    data[..2].copy_from_slice(&0xD83Du16.to_le_bytes());
+    let broken = RefereeFrameCmdData::CustomInfo {
+        sender_id: EndpointId::RedHero,
+        receiver_id: EndpointId::RedHeroClient,
+        data,
+    };
+    assert_eq!(
+        CustomInfo::from_cmd_data(&broken),
+        Err(CustomInfoError::InvalidUtf16)
+    );
+}
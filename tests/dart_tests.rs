@@ -0,0 +1,80 @@
+use core::time::Duration;
+
+use deku::DekuContainerWrite;
+use rm_referee_protocol::*;
+
+fn game_status(progress: GameProgress, stage_remain_time: u16) -> RefereeFrameCmdData {
+    RefereeFrameCmdData::GameStatus {
+        game_type: GameType::RMUC,
+        game_progress: progress,
+        stage_remain_time,
+        sync_time_stamp: 0,
+    }
+}
+
+fn dart_client_cmd(gate: DartLaunchGate, latest_launch_cmd_time: u16) -> RefereeFrameCmdData {
+    RefereeFrameCmdData::DartClientCmd {
+        dart_launch_opening_status: gate,
+        target_change_time: 0,
+        latest_launch_cmd_time,
+    }
+}
+
+fn dart_info(remaining: u8, target: DartSelectionTarget) -> RefereeFrameCmdData {
+    RefereeFrameCmdData::DartInfo {
+        dart_remaining_time: remaining,
+        dart_last_hit_target: DartTarget::None,
+        opponent_target_hit_count: 0,
+        dart_selected_target: target,
+    }
+}
+
+#[test]
+fn test_020a_gate_status_bytes() {
+    let v = dart_client_cmd(DartLaunchGate::Transitioning, 300);
+    let bytes = v.to_bytes().unwrap();
+    assert_eq!(bytes, [0x0A, 0x02, 0x02, 0x00, 0x00, 0x00, 0x2C, 0x01]);
+    let parsed = RefereeFrameCmdData::try_from(bytes.as_slice()).unwrap();
+    assert_eq!(v, parsed);
+}
+
+#[test]
+fn test_dart_station_state_transitions() {
+    let mut station = DartStation::new();
+    assert_eq!(station.state(), DartStationState::Inactive);
+    assert!(!station.update(&RefereeFrameCmdData::RobotPos {
+        x: 0.0,
+        y: 0.0,
+        angle: 0.0
+    }));
+
+    station.update(&dart_client_cmd(DartLaunchGate::Open, 0));
+    station.update(&dart_info(20, DartSelectionTarget::BaseFixed));
+    // 比赛尚未开始
+    assert_eq!(station.state(), DartStationState::Inactive);
+
+    station.update(&game_status(GameProgress::InProgress, 240));
+    assert_eq!(station.state(), DartStationState::Ready);
+    assert!(station.launch_permitted());
+    assert_eq!(
+        station.launch_window_remaining(),
+        Some(Duration::from_secs(20))
+    );
+    assert_eq!(
+        station.selected_target(),
+        Some(DartSelectionTarget::BaseFixed)
+    );
+    assert_eq!(station.since_launch_cmd(), None);
+
+    station.update(&dart_info(0, DartSelectionTarget::BaseFixed));
+    assert_eq!(station.state(), DartStationState::Expired);
+    assert_eq!(station.launch_window_remaining(), None);
+
+    station.update(&dart_client_cmd(DartLaunchGate::Transitioning, 250));
+    assert_eq!(station.state(), DartStationState::Transitioning);
+    assert_eq!(station.since_launch_cmd(), Some(Duration::from_secs(10)));
+
+    station.update(&dart_client_cmd(DartLaunchGate::Closed, 250));
+    assert_eq!(station.state(), DartStationState::Closed);
+    assert!(!station.launch_permitted());
+}
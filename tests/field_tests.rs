@@ -0,0 +1,49 @@
+use rm_referee_protocol::*;
+
+#[test]
+fn test_field_wire_encodings_round_trip_losslessly() {
+    for cm in [0u16, 1, 99, 1_234, 2_799, u16::MAX] {
+        assert_eq!(FieldPoint::from_cm(cm, cm).to_cm(), Ok((cm, cm)));
+    }
+    for dm in [0u16, 7, 149, 280, u16::MAX] {
+        assert_eq!(FieldPoint::from_dm(dm, dm).to_dm(), Ok((dm, dm)));
+    }
+    let p = FieldPoint::new(12.345, 6.78);
+    assert_eq!(p.to_cm(), Ok((1_235, 678)));
+    assert_eq!(p.to_dm(), Ok((123, 68)));
+    assert_eq!(
+        FieldPoint::new(-0.2, 1.0).to_cm(),
+        Err(FieldError::OutOfRange)
+    );
+    assert_eq!(
+        FieldPoint::new(f32::NAN, 1.0).to_dm(),
+        Err(FieldError::NotFinite)
+    );
+}
+
+#[test]
+fn test_field_dm_deltas_fit_i8() {
+    let a = FieldPoint::new(1.0, 2.0);
+    let b = FieldPoint::new(13.7, -0.0);
+    assert_eq!(a.dm_delta_to(b), Ok((127, -20)));
+    assert_eq!(a.offset_dm((127, -20)).to_dm(), b.to_dm());
+    assert_eq!(
+        a.dm_delta_to(FieldPoint::new(13.8, 2.0)),
+        Err(FieldError::OutOfRange)
+    );
+}
+
+#[test]
+fn test_field_bounds_and_mirroring_follow_game_type() {
+    let p = FieldPoint::new(20.0, 10.0);
+    assert_eq!(p.in_field(GameType::RMUC), Ok(p));
+    assert_eq!(p.in_field(GameType::RMUL3v3), Err(FieldError::OutOfField));
+    assert_eq!(
+        p.in_field(GameType::RMUT),
+        Err(FieldError::UnknownField(GameType::RMUT))
+    );
+
+    let mirrored = p.mirrored(GameType::RMUC).unwrap();
+    assert_eq!(mirrored, FieldPoint::new(8.0, 5.0));
+    assert_eq!(mirrored.mirrored(GameType::RMUC), Ok(p));
+}
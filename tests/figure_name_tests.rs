@@ -0,0 +1,52 @@
+use rm_referee_protocol::*;
+
+#[test]
+fn test_allocator_unique_per_layer_and_free() {
+    let mut names = FigureNameAllocator::new();
+    let a = names.allocate(1).unwrap();
+    let b = names.allocate(1).unwrap();
+    let c = names.allocate(2).unwrap();
+    assert_ne!(a, b);
+    assert_eq!((a[0], c[0]), (b'1', b'2'));
+    assert!(names.free(&a));
+    assert!(!names.free(&a));
+    // 刚释放的名称不会被立即复用
+    assert_ne!(names.allocate(1).unwrap(), a);
+    assert_eq!(names.allocate(10), Err(NameError::LayerOutOfRange));
+}
+
+#[test]
+fn test_allocator_labels_are_stable() {
+    let mut first = FigureNameAllocator::new();
+    let mut second = FigureNameAllocator::new();
+    let heat = first.named(3, "heat_bar").unwrap();
+    second.allocate(3).unwrap();
+    // 与分配顺序无关
+    assert_eq!(second.named(3, "heat_bar").unwrap(), heat);
+    assert_eq!(first.named(3, "heat_bar").unwrap(), heat);
+    assert_eq!(first.label_of(&heat), Some("heat_bar"));
+    // 换图层后名称随之改变
+    let moved = first.named(4, "heat_bar").unwrap();
+    assert_eq!(moved[0], b'4');
+    assert!(!first.is_owned(&heat));
+}
+
+#[test]
+fn test_allocator_detects_foreign_collisions() {
+    let mut names = FigureNameAllocator::new();
+    let ours = names.named(1, "crosshair").unwrap();
+    let foreign = Figure::circle(ours, 1, FigureColor::Green, 2, ScreenPoint::CENTER, 10)
+        .to_interaction(FigureOperateType::Add);
+    assert_eq!(names.observe(&foreign), Err(NameError::Collision(ours)));
+
+    let other = Figure::circle(*b"1zz", 1, FigureColor::Green, 2, ScreenPoint::CENTER, 10)
+        .to_interaction(FigureOperateType::Add);
+    let data = RobotInteractionUserData::Figures2([foreign, other]);
+    assert_eq!(names.observe_user_data(&data), [ours]);
+    assert!(names.is_foreign(b"1zz"));
+    // 外部已占用的名称不会被分配
+    for _ in 0..3842 {
+        assert_ne!(names.allocate(1).unwrap(), *b"1zz");
+    }
+    assert_eq!(names.allocate(1), Err(NameError::Exhausted));
+}
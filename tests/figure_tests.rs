@@ -0,0 +1,292 @@
+use deku::DekuContainerWrite;
+use rm_referee_protocol::*;
+
+mod common;
+use common::pt;
+
+fn roundtrip(fig: InteractionFigure) -> InteractionFigure {
+    let bytes = fig.to_bytes().unwrap();
+    assert_eq!(bytes.len(), 15);
+    InteractionFigure::try_from(bytes.as_slice()).unwrap()
+}
+
+#[test]
+fn test_figure_builders_map_details() {
+    let line = Figure::line(
+        *b"ln0",
+        1,
+        FigureColor::Green,
+        3,
+        pt(100, 200),
+        pt(1800, 900),
+    )
+    .to_interaction(FigureOperateType::Add);
+    assert_eq!(line.figure_type, FigureType::Line);
+    assert_eq!((line.start_x, line.start_y), (100, 200));
+    assert_eq!((line.details_d, line.details_e), (1800, 900));
+
+    let circle = Figure::circle(*b"ci0", 2, FigureColor::Cyan, 2, pt(960, 540), 50)
+        .to_interaction(FigureOperateType::Add);
+    assert_eq!(circle.details_c, 50);
+
+    let arc = Figure::arc(
+        *b"ar0",
+        3,
+        FigureColor::Yellow,
+        4,
+        ScreenPoint::CENTER,
+        (30, 330),
+        (120, 80),
+    )
+    .to_interaction(FigureOperateType::Modify);
+    assert_eq!((arc.details_a, arc.details_b), (30, 330));
+    assert_eq!((arc.details_d, arc.details_e), (120, 80));
+
+    let text = Figure::text(*b"tx0", 4, FigureColor::White, 20, 2, pt(50, 800), 12)
+        .to_interaction(FigureOperateType::Add);
+    assert_eq!(text.figure_type, FigureType::Char);
+    assert_eq!((text.details_a, text.details_b), (20, 12));
+}
+
+#[test]
+fn test_figure_decode_roundtrip() {
+    let figures = [
+        Figure::rect(*b"rc0", 0, FigureColor::Orange, 1, pt(10, 20), pt(30, 40)),
+        Figure::ellipse(*b"el0", 5, FigureColor::Pink, 2, pt(600, 400), (90, 45)),
+        Figure::int(*b"in0", 6, FigureColor::Magenta, 24, 3, pt(1500, 700), 1234),
+        Figure::float(*b"fl0", 7, FigureColor::Black, 24, 3, pt(1500, 650), 12.5),
+    ];
+    for fig in figures {
+        let wire = roundtrip(fig.to_interaction(FigureOperateType::Add));
+        assert_eq!(Figure::try_from(&wire), Ok(fig));
+    }
+}
+
+#[test]
+fn test_figure_decode_rejects_off_screen_points() {
+    let mut wire = Figure::rect(*b"rc1", 0, FigureColor::Green, 1, pt(10, 20), pt(30, 40))
+        .to_interaction(FigureOperateType::Add);
+    wire.start_x = 1920;
+    assert_eq!(
+        Figure::try_from(&wire),
+        Err(FigureError::OffScreen { x: 1920, y: 20 })
+    );
+    wire.start_x = 10;
+    wire.details_e = 1080;
+    assert_eq!(
+        Figure::try_from(&wire),
+        Err(FigureError::OffScreen { x: 30, y: 1080 })
+    );
+
+    let mut text = TextFigure::new(*b"tx3", 0, FigureColor::Green, 20, 2, pt(0, 0), "A")
+        .unwrap()
+        .figure()
+        .to_interaction(FigureOperateType::Add);
+    text.start_y = 2047;
+    assert_eq!(
+        TextFigure::from_custom_character(&text, &[b'A'; 30]),
+        Err(TextError::Figure(FigureError::OffScreen { x: 0, y: 2047 }))
+    );
+}
+
+#[test]
+fn test_figure_int_value_negative_and_extremes() {
+    let mut fig = Figure::int(
+        *b"in1",
+        1,
+        FigureColor::Green,
+        20,
+        2,
+        ScreenPoint::BOTTOM_LEFT,
+        0,
+    )
+    .to_interaction(FigureOperateType::Add);
+    for value in [-1, -1024, 1023, 1 << 21, i32::MIN, i32::MAX, -123_456_789] {
+        fig.set_int_value(value);
+        assert!(fig.details_c < 1 << 10);
+        assert!(fig.details_d < 1 << 11 && fig.details_e < 1 << 11);
+        assert_eq!(roundtrip(fig).int_value(), value);
+    }
+    fig.set_int_value(-1);
+    assert_eq!(
+        (fig.details_c, fig.details_d, fig.details_e),
+        (0x3FF, 0x7FF, 0x7FF)
+    );
+}
+
+#[test]
+fn test_figure_wire_bytes_match_spec() {
+    let fig = Figure::int(
+        *b"abc",
+        0,
+        FigureColor::SelfColor,
+        20,
+        2,
+        pt(100, 200),
+        1000,
+    )
+    .to_interaction(FigureOperateType::Add);
+    let bytes = fig.to_bytes().unwrap();
+    assert_eq!(&bytes[..3], b"abc");
+    // 配置 1：操作 1（bit 0-2），类型 6（bit 3-5），字体大小 20（bit 14-22）
+    assert_eq!(bytes[3..7], (1u32 | 6 << 3 | 20 << 14).to_le_bytes());
+    // 配置 2：线宽 2（bit 0-9），起点 x 100（bit 10-20），起点 y 200（bit 21-31）
+    assert_eq!(bytes[7..11], [0x02, 0x90, 0x01, 0x19]);
+    assert_eq!(bytes[11..15], [0xe8, 0x03, 0x00, 0x00]);
+
+    let mut fig = fig;
+    for (value, wire) in [
+        (0x1234_5678, [0x78, 0x56, 0x34, 0x12]),
+        (-1, [0xff, 0xff, 0xff, 0xff]),
+        (-1000, [0x18, 0xfc, 0xff, 0xff]),
+        (i32::MIN, [0x00, 0x00, 0x00, 0x80]),
+        (i32::MAX, [0xff, 0xff, 0xff, 0x7f]),
+    ] {
+        fig.set_int_value(value);
+        let bytes = fig.to_bytes().unwrap();
+        assert_eq!(bytes[11..15], wire);
+        assert_eq!(roundtrip(fig).int_value(), value);
+    }
+
+    // 各位域取最大值时互不重叠
+    let mut max = Figure::circle(
+        *b"max",
+        15,
+        FigureColor::White,
+        1023,
+        ScreenPoint::TOP_RIGHT,
+        1023,
+    )
+    .to_interaction(FigureOperateType::Delete);
+    (max.start_x, max.start_y) = (0x7FF, 0x7FF);
+    let bytes = max.to_bytes().unwrap();
+    assert_eq!(
+        bytes[3..7],
+        (3u32 | 2 << 3 | 15 << 6 | 8 << 10).to_le_bytes()
+    );
+    assert_eq!(bytes[7..11], u32::MAX.to_le_bytes());
+    assert_eq!(bytes[11..15], 1023u32.to_le_bytes());
+}
+
+#[test]
+fn test_figure_float_value_rounding_and_overflow() {
+    let mut fig = Figure::float(
+        *b"fl1",
+        1,
+        FigureColor::Green,
+        20,
+        2,
+        ScreenPoint::BOTTOM_LEFT,
+        0.0,
+    )
+    .to_interaction(FigureOperateType::Add);
+    assert!(fig.set_float_value(-12.345));
+    assert_eq!(fig.int_value(), -12345);
+    assert_eq!(roundtrip(fig).float_value(), -12.345);
+    // 1.001 * 1000 在 f32 下略小于 1001，需四舍五入而非截断
+    assert!(fig.set_float_value(1.001));
+    assert_eq!(fig.int_value(), 1001);
+
+    assert!(!fig.set_float_value(2_147_484.0));
+    assert!(!fig.set_float_value(f32::NAN));
+    assert_eq!(fig.int_value(), 1001);
+    assert_eq!(float_to_milli(-2_147_483.5), Some(-2_147_483_500));
+    assert_eq!(float_to_milli(-2_147_484.0), None);
+
+    let saturated = Figure::float(
+        *b"fl2",
+        1,
+        FigureColor::Green,
+        20,
+        2,
+        ScreenPoint::BOTTOM_LEFT,
+        -1e9,
+    )
+    .to_interaction(FigureOperateType::Add);
+    assert_eq!(saturated.int_value(), i32::MIN);
+}
+
+#[test]
+fn test_text_figure_encode_decode() {
+    let text = TextFigure::new(
+        *b"tx1",
+        2,
+        FigureColor::White,
+        20,
+        2,
+        pt(100, 700),
+        "HEAT 80%",
+    )
+    .unwrap();
+    let RobotInteractionUserData::CustomCharacter { figure, data } =
+        text.to_user_data(FigureOperateType::Add)
+    else {
+        unreachable!()
+    };
+    assert_eq!(figure.figure_type, FigureType::Char);
+    assert_eq!((figure.details_a, figure.details_b), (20, 8));
+    assert_eq!(&data[..8], b"HEAT 80%");
+    assert!(data[8..].iter().all(|&b| b == 0));
+
+    let decoded = TextFigure::from_custom_character(&roundtrip(figure), &data).unwrap();
+    assert_eq!(decoded.text(), "HEAT 80%");
+    assert_eq!(decoded, text);
+}
+
+#[test]
+fn test_text_figure_validation() {
+    let new = |s: &str| {
+        TextFigure::new(
+            *b"tx2",
+            0,
+            FigureColor::Green,
+            20,
+            2,
+            ScreenPoint::BOTTOM_LEFT,
+            s,
+        )
+    };
+    assert!(new(&"A".repeat(30)).is_ok());
+    assert_eq!(new(&"A".repeat(31)), Err(TextError::TooLong));
+    assert_eq!(new("热量"), Err(TextError::InvalidChar));
+    assert_eq!(new("a\tb"), Err(TextError::InvalidChar));
+
+    // 不会失败的构造：非法字符替换为 '?'，超长截断
+    let lossy = |s: &str| {
+        TextFigure::new_lossy(
+            *b"tx2",
+            0,
+            FigureColor::Green,
+            20,
+            2,
+            ScreenPoint::BOTTOM_LEFT,
+            s,
+        )
+    };
+    assert_eq!(lossy("HP 80"), new("HP 80").unwrap());
+    assert_eq!(lossy("热量 a\tb").text(), "?? a?b");
+    assert_eq!(lossy(&"B".repeat(40)).text(), "B".repeat(30));
+
+    let line = Figure::line(
+        *b"ln1",
+        0,
+        FigureColor::Green,
+        1,
+        ScreenPoint::BOTTOM_LEFT,
+        pt(1, 1),
+    )
+    .to_interaction(FigureOperateType::Add);
+    assert_eq!(
+        TextFigure::from_custom_character(&line, &[0; 30]),
+        Err(TextError::NotText)
+    );
+    let mut fig = new("ok")
+        .unwrap()
+        .figure()
+        .to_interaction(FigureOperateType::Add);
+    fig.details_b = 31;
+    assert_eq!(
+        TextFigure::from_custom_character(&fig, &[b'A'; 30]),
+        Err(TextError::TooLong)
+    );
+}
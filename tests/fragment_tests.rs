@@ -0,0 +1,145 @@
+use core::time::Duration;
+
+use rm_referee_protocol::*;
+
+mod common;
+use common::ms;
+
+const TIMEOUT: Duration = Duration::from_millis(500);
+
+fn blob(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i * 7) as u8).collect()
+}
+
+#[test]
+fn test_fragment_split_respects_payload_limit() {
+    let mut tx = Fragmenter::default();
+    let frames = tx
+        .split(EndpointId::RedSentry, EndpointId::RedHero, &blob(500))
+        .unwrap();
+    assert_eq!(frames.len(), 500usize.div_ceil(FRAGMENT_CHUNK_LEN));
+    for frame in &frames {
+        let RefereeFrameCmdData::RobotInteractionData {
+            data_cmd_id,
+            user_data,
+            ..
+        } = frame
+        else {
+            panic!("unexpected {frame:?}");
+        };
+        assert_eq!(*data_cmd_id, FRAGMENT_DATA_CMD_ID);
+        assert!(user_data.len() <= 112);
+    }
+    assert_eq!(
+        tx.split(EndpointId::RedSentry, EndpointId::RedHero, &[]),
+        Err(FragmentError::Empty)
+    );
+    assert_eq!(
+        tx.split(EndpointId::RedSentry, EndpointId::RedHero, &blob(30_000)),
+        Err(FragmentError::TooLarge(30_000))
+    );
+}
+
+#[test]
+fn test_fragment_reassembles_reordered_and_duplicated_fragments() {
+    let data = blob(400);
+    let mut tx = Fragmenter::default();
+    let mut frames = tx
+        .split(EndpointId::RedSentry, EndpointId::RedHero, &data)
+        .unwrap();
+    frames.reverse();
+    let dup = frames[0].clone();
+    frames.insert(1, dup);
+
+    let mut rx = Reassembler::new(TIMEOUT);
+    let mut done = Vec::new();
+    for (i, frame) in frames.iter().enumerate() {
+        done.extend(rx.push(frame, ms(i as u64 * 10)));
+    }
+    assert_eq!(done.len(), 1);
+    assert_eq!(done[0].sender_id, EndpointId::RedSentry);
+    assert_eq!(done[0].data, data);
+    assert_eq!(rx.pending_len(), 0);
+}
+
+#[test]
+fn test_fragment_lost_fragment_times_out_and_later_messages_still_arrive() {
+    let mut tx = Fragmenter::default();
+    let mut rx = Reassembler::new(TIMEOUT);
+    let first = tx
+        .split(EndpointId::RedSentry, EndpointId::RedHero, &blob(300))
+        .unwrap();
+    for frame in first.iter().skip(1) {
+        assert_eq!(rx.push(frame, ms(0)), None);
+    }
+    assert_eq!(rx.pending_len(), 1);
+    assert_eq!(rx.expire(ms(400)), 0);
+    assert_eq!(rx.expire(ms(600)), 1);
+    assert_eq!(rx.dropped(), 1);
+
+    // 丢失的分片迟到：只开始新的未完成消息，不会产生错误的重组结果
+    assert_eq!(rx.push(&first[0], ms(700)), None);
+
+    let second = tx
+        .split(EndpointId::RedSentry, EndpointId::RedHero, &blob(150))
+        .unwrap();
+    let out = second.iter().filter_map(|f| rx.push(f, ms(800))).last();
+    assert_eq!(out.map(|m| m.data), Some(blob(150)));
+}
+
+#[test]
+fn test_fragment_interleaved_senders_and_foreign_ids() {
+    let mut a = Fragmenter::default();
+    let mut b = Fragmenter::default();
+    let fa = a
+        .split(EndpointId::RedHero, EndpointId::RedSentry, &blob(250))
+        .unwrap();
+    let fb = b
+        .split(EndpointId::RedEngineer, EndpointId::RedSentry, &[1; 200])
+        .unwrap();
+    let mut rx = Reassembler::new(TIMEOUT);
+    let other = RefereeFrameCmdData::RobotInteractionData {
+        data_cmd_id: 0x0201,
+        sender_id: EndpointId::RedHero,
+        receiver_id: EndpointId::RedSentry,
+        user_data: vec![0, 0, 1, 9],
+    };
+    assert_eq!(rx.push(&other, ms(0)), None);
+
+    let mut out = Vec::new();
+    for pair in fa.iter().zip(fb.iter().chain(core::iter::repeat(&fb[0]))) {
+        out.extend(rx.push(pair.0, ms(0)));
+        out.extend(rx.push(pair.1, ms(0)));
+    }
+    assert_eq!(out.len(), 2);
+    assert!(
+        out.iter()
+            .any(|m| m.sender_id == EndpointId::RedHero && m.data == blob(250))
+    );
+    assert!(
+        out.iter()
+            .any(|m| m.sender_id == EndpointId::RedEngineer && m.data == [1; 200])
+    );
+}
+
+#[test]
+fn test_fragment_data_cmd_id_must_be_robot_message_id() {
+    assert_eq!(
+        Fragmenter::new(0x0100),
+        Err(MessageError::IdOutOfRange(0x0100))
+    );
+    assert_eq!(
+        Reassembler::new(TIMEOUT).with_data_cmd_id(0x0300),
+        Err(MessageError::IdOutOfRange(0x0300))
+    );
+
+    let mut tx = Fragmenter::new(0x0233).unwrap();
+    let mut rx = Reassembler::new(TIMEOUT).with_data_cmd_id(0x0233).unwrap();
+    let frames = tx
+        .split(EndpointId::RedHero, EndpointId::RedSentry, &[5; 10])
+        .unwrap();
+    assert_eq!(
+        rx.push(&frames[0], ms(0)).map(|m| m.data),
+        Some(vec![5; 10])
+    );
+}
@@ -0,0 +1,127 @@
+use rm_referee_protocol::*;
+
+mod common;
+use common::ms;
+
+fn status(cooling: u16, limit: u16) -> RefereeFrameCmdData {
+    RefereeFrameCmdData::RobotStatus {
+        robot_id: RobotID::RedInfantry3,
+        robot_level: 1,
+        current_hp: 200,
+        maximum_hp: 200,
+        shooter_barrel_cooling_value: cooling,
+        shooter_barrel_heat_limit: limit,
+        chassis_power_limit: 60,
+        power_management_gimbal_output: true,
+        power_management_chassis_output: true,
+        power_management_shooter_output: true,
+    }
+}
+
+fn heat(h1: u16, h42: u16) -> RefereeFrameCmdData {
+    RefereeFrameCmdData::PowerHeatData {
+        buffer_energy: 60,
+        shooter_17mm_1_barrel_heat: h1,
+        shooter_17mm_2_barrel_heat: 0,
+        shooter_42mm_barrel_heat: h42,
+    }
+}
+
+fn shoot(shooter_number: ShooterNumber) -> RefereeFrameCmdData {
+    RefereeFrameCmdData::ShootData {
+        bullet_type: BulletType::Bullet17mm,
+        shooter_number,
+        launching_frequency: 10,
+        initial_speed: 25.0,
+    }
+}
+
+#[test]
+fn test_heat_no_shots_before_sync() {
+    let mut model = HeatModel::new(ShooterNumber::Shooter17mm1);
+    assert_eq!(model.shots_allowed(ms(0)), 0);
+    model.update(&status(40, 200), ms(0));
+    assert_eq!(model.shots_allowed(ms(0)), 0);
+    model.update(&heat(35, 0), ms(10));
+    assert_eq!(model.heat(ms(10)), Some(35));
+    assert_eq!(model.shots_allowed(ms(10)), 16);
+}
+
+#[test]
+fn test_heat_local_shots_and_cooling_between_updates() {
+    let mut model = HeatModel::new(ShooterNumber::Shooter17mm1);
+    model.update(&status(45, 100), ms(0));
+    model.update(&heat(50, 0), ms(0));
+    for t in [10, 20, 30] {
+        model.record_shot(ms(t));
+    }
+    assert_eq!(model.heat(ms(30)), Some(80));
+    assert_eq!(model.shots_allowed(ms(30)), 2);
+    // 不足一个结算周期不计冷却
+    assert_eq!(model.heat(ms(99)), Some(80));
+    // 每 100ms 冷却 4.5
+    assert_eq!(model.heat(ms(100)), Some(76));
+    assert_eq!(model.heat(ms(200)), Some(71));
+    assert_eq!(model.heat(ms(2_000)), Some(0));
+    assert_eq!(model.shots_allowed(ms(2_000)), 10);
+
+    // 0x0207 确认本地已记录的发射，不重复计入
+    assert!(!model.update(&shoot(ShooterNumber::Shooter17mm1), ms(40)));
+    assert!(!model.update(&shoot(ShooterNumber::Shooter17mm2), ms(40)));
+    assert_eq!(model.heat(ms(40)), Some(80));
+    // 未在本地记录的发射由 0x0207 计入
+    model.update(&shoot(ShooterNumber::Shooter17mm1), ms(45));
+    model.update(&shoot(ShooterNumber::Shooter17mm1), ms(45));
+    model.update(&shoot(ShooterNumber::Shooter17mm1), ms(45));
+    assert_eq!(model.heat(ms(45)), Some(90));
+    assert_eq!(model.shots_allowed(ms(45)), 1);
+}
+
+#[test]
+fn test_heat_resync_replaces_estimate_and_keeps_recent_shots() {
+    let mut model = HeatModel::new(ShooterNumber::Shooter42mm);
+    model.update(&status(20, 240), ms(0));
+    model.update(&heat(0, 0), ms(0));
+    model.record_shot(ms(50));
+    assert_eq!(model.shots_allowed(ms(50)), 1);
+    model.record_shot(ms(300));
+    assert_eq!(model.heat(ms(300)), Some(194));
+
+    // 上报热量的增长只确认了 50ms 的发射，300ms 的发射同步后仍计入
+    model.update(&heat(0, 98), ms(350));
+    assert_eq!(model.heat(ms(350)), Some(198));
+    assert_eq!(model.shots_allowed(ms(350)), 0);
+    model.update(&heat(0, 96), ms(450));
+    assert_eq!(model.heat(ms(450)), Some(196));
+    // 超过 latency 仍未被上报确认的发射不再计入
+    model.update(&heat(0, 92), ms(650));
+    assert_eq!(model.heat(ms(650)), Some(92));
+    assert_eq!(model.shots_allowed(ms(650)), 1);
+
+    // 超出上限时不允许发射
+    model.update(&heat(0, 250), ms(750));
+    assert!(!model.can_shoot(ms(750)));
+}
+
+#[test]
+fn test_heat_lagging_report_does_not_release_unconfirmed_shots() {
+    let mut model = HeatModel::new(ShooterNumber::Shooter17mm1);
+    model.update(&status(40, 100), ms(0));
+    model.update(&heat(0, 0), ms(0));
+    for t in [60, 70, 80, 90, 95] {
+        model.record_shot(ms(t));
+    }
+    assert_eq!(model.shots_allowed(ms(95)), 5);
+
+    // 裁判系统的热量滞后 150ms：100ms 与 200ms 的上报仍为 0
+    model.update(&heat(0, 0), ms(100));
+    assert_eq!(model.shots_allowed(ms(100)), 5);
+    model.update(&heat(0, 0), ms(200));
+    assert_eq!(model.heat(ms(200)), Some(50));
+    assert_eq!(model.shots_allowed(ms(200)), 5);
+
+    // 300ms 的上报包含全部 5 发（已冷却一个周期），不再重复计入
+    model.update(&heat(46, 0), ms(300));
+    assert_eq!(model.heat(ms(300)), Some(46));
+    assert_eq!(model.shots_allowed(ms(300)), 5);
+}
@@ -0,0 +1,78 @@
+use rm_referee_protocol::*;
+
+mod common;
+use common::{line, pt};
+
+fn figure_count(data: &[RobotInteractionUserData]) -> usize {
+    data.iter()
+        .map(|d| match d {
+            RobotInteractionUserData::Figure(_) => 1,
+            RobotInteractionUserData::Figures2(_) => 2,
+            RobotInteractionUserData::Figures5(_) => 5,
+            RobotInteractionUserData::Figures7(_) => 7,
+            _ => 0,
+        })
+        .sum()
+}
+
+#[test]
+fn test_hud_scene_minimal_diff() {
+    let mut scene = HudScene::new();
+    scene.set(line(b"l00", 10));
+    scene.set(line(b"l01", 20));
+    scene.set(line(b"l02", 30));
+    let first = scene.update();
+    assert_eq!(figure_count(&first), 3);
+    // 3 = 2 + 1
+    assert_eq!(first.len(), 2);
+
+    // 无变化时不发送
+    scene.set(line(b"l01", 20));
+    assert!(scene.update().is_empty());
+
+    scene.set(line(b"l01", 25));
+    scene.remove(b"l02");
+    let ops = scene.diff();
+    assert_eq!(ops.len(), 2);
+    assert_eq!(ops[0].operate_type, FigureOperateType::Delete);
+    assert_eq!(ops[0].element.name(), *b"l02");
+    assert_eq!(ops[1].operate_type, FigureOperateType::Modify);
+    let RobotInteractionUserData::Figures2(figs) = &scene.update()[0] else {
+        panic!("expected 0x0102");
+    };
+    assert_eq!(figs[1].start_x, 25);
+
+    scene.invalidate();
+    let resync = scene.update();
+    assert_eq!(figure_count(&resync), 2);
+}
+
+#[test]
+fn test_hud_scene_packing_and_text() {
+    let mut scene = HudScene::new();
+    for i in 0..10u8 {
+        scene.set(line(&[b'n', b'0', b'0' + i], u16::from(i) * 10));
+    }
+    scene.set(TextFigure::new(*b"txt", 2, FigureColor::White, 20, 2, pt(50, 50), "AMMO").unwrap());
+    let data = scene.update();
+    // 10 = 5 + 5，字符图形单独一条
+    assert_eq!(data.len(), 3);
+    assert!(matches!(data[0], RobotInteractionUserData::Figures5(_)));
+    assert!(matches!(data[1], RobotInteractionUserData::Figures5(_)));
+    assert_eq!(data[2].data_cmd_id(), 0x0110);
+
+    // 编码为 0x0301 后能按原样解析
+    let cmd = data[0]
+        .to_cmd_data(EndpointId::RedHero, EndpointId::RedHeroClient)
+        .unwrap();
+    assert_eq!(cmd.as_robot_interaction(), Some(data[0].clone()));
+
+    // 删除字符图形走普通图形删除
+    scene.remove(b"txt");
+    let data = scene.update();
+    let RobotInteractionUserData::Figure(fig) = data[0] else {
+        panic!("expected 0x0101");
+    };
+    assert_eq!(fig.operate_type, FigureOperateType::Delete);
+    assert_eq!(fig.figure_type, FigureType::Char);
+}
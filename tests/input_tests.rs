@@ -0,0 +1,132 @@
+use rm_referee_protocol::*;
+
+mod common;
+use common::ms;
+
+fn remote_control(keyboard_value: u16, left: u8, right: u8) -> RefereeFrameCmdData {
+    RefereeFrameCmdData::RemoteControl {
+        mouse_x: 0,
+        mouse_y: 0,
+        mouse_z: 0,
+        left_button_down: left,
+        right_button_down: right,
+        keyboard_value,
+        reserved: 0,
+    }
+}
+
+#[test]
+fn test_keys_bit_order() {
+    let v = remote_control(0b_0000_0001_0001_0001, 1, 0);
+    assert_eq!(v.keys(), Some(Keys::W | Keys::SHIFT | Keys::R));
+    assert_eq!(v.mouse_buttons(), Some(MouseButtons::LEFT));
+    assert_eq!(Keys::B.bits(), 0x8000);
+    assert_eq!(Keys::CTRL.bits(), 0x0020);
+}
+
+#[test]
+fn test_keys_and_mouse_setters() {
+    let mut v = RefereeFrameCmdData::SimulateControl {
+        keyboard_value: 0,
+        mouse_x_pos: 960,
+        mouse_left_state: 0,
+        mouse_y_pos: 540,
+        mouse_right_state: 0,
+        reserved: 0,
+    };
+    assert!(v.set_keys(Keys::Q | Keys::E));
+    assert!(v.set_mouse_buttons(MouseButtons::RIGHT));
+    let RefereeFrameCmdData::SimulateControl {
+        keyboard_value,
+        mouse_left_state,
+        mouse_right_state,
+        ..
+    } = v
+    else {
+        unreachable!()
+    };
+    assert_eq!(keyboard_value, 0x00C0);
+    assert_eq!((mouse_left_state, mouse_right_state), (0, 1));
+
+    let mut pos = RefereeFrameCmdData::RobotPos {
+        x: 0.0,
+        y: 0.0,
+        angle: 0.0,
+    };
+    assert_eq!(pos.keys(), None);
+    assert!(!pos.set_keys(Keys::W));
+}
+
+#[test]
+fn test_map_command_key_code() {
+    let mut v = RefereeFrameCmdData::MapCommand {
+        target_position_x: 0.0,
+        target_position_y: 0.0,
+        cmd_keyboard: 0,
+        target_robot_id: RobotID::RedHero,
+        cmd_source: EndpointId::RedHeroClient,
+    };
+    assert_eq!(v.map_command_key(), None);
+    assert!(v.set_map_command_key(Some(Keys::G)));
+    assert_eq!(v.map_command_key(), Some(Keys::G));
+    assert!(!v.set_map_command_key(Some(Keys::G | Keys::W)));
+    assert_eq!(Keys::from_key_code(0x10), Some(Keys::SHIFT));
+    assert_eq!(Keys::from_key_code(b'w'), Some(Keys::W));
+}
+
+fn mouse_frame(mouse_x: i16, mouse_y: i16) -> RefereeFrameCmdData {
+    RefereeFrameCmdData::RemoteControl {
+        mouse_x,
+        mouse_y,
+        mouse_z: 0,
+        left_button_down: 0,
+        right_button_down: 0,
+        keyboard_value: 0,
+        reserved: 0,
+    }
+}
+
+#[test]
+fn test_input_tracker_edges_and_hold() {
+    let mut tracker = InputTracker::new(ms(200));
+
+    let e = tracker
+        .update(&remote_control(Keys::W.bits(), 1, 0), ms(0))
+        .unwrap();
+    assert_eq!(e.pressed, Keys::W);
+    assert_eq!(e.buttons_pressed, MouseButtons::LEFT);
+
+    // 中间帧丢失：W 松开、Shift 按下在同一次更新中体现
+    let e = tracker
+        .update(&remote_control(Keys::SHIFT.bits(), 1, 0), ms(100))
+        .unwrap();
+    assert_eq!(e.pressed, Keys::SHIFT);
+    assert_eq!(e.released, Keys::W);
+    assert!(e.buttons_pressed.is_empty());
+    assert_eq!(tracker.held_for(Keys::SHIFT, ms(150)), Some(ms(50)));
+    assert_eq!(
+        tracker.button_held_for(MouseButtons::LEFT, ms(150)),
+        Some(ms(150))
+    );
+    assert_eq!(tracker.held_for(Keys::W, ms(150)), None);
+    assert!(tracker.update(&mouse_frame(0, 0), ms(150)).is_some());
+    assert!(tracker.keys().is_empty());
+}
+
+#[test]
+fn test_input_tracker_mouse_and_timeout() {
+    let mut tracker = InputTracker::new(ms(200)).with_sensitivity(0.5, -1.0, 1.0);
+    tracker.update(&mouse_frame(10, 4), ms(0));
+    tracker.update(&mouse_frame(-4, 2), ms(33));
+    assert_eq!(tracker.take_mouse_delta(), (3.0, -6.0, 0.0));
+    assert_eq!(tracker.mouse_delta(), (0.0, 0.0, 0.0));
+
+    tracker.update(&remote_control((Keys::A | Keys::D).bits(), 0, 1), ms(66));
+    assert!(tracker.poll(ms(200)).is_empty());
+    let e = tracker.poll(ms(300));
+    assert_eq!(e.released, Keys::A | Keys::D);
+    assert_eq!(e.buttons_released, MouseButtons::RIGHT);
+    assert!(!tracker.is_linked(ms(300)));
+    assert!(tracker.keys().is_empty());
+    assert!(tracker.poll(ms(400)).is_empty());
+}
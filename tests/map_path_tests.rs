@@ -0,0 +1,75 @@
+use deku::prelude::*;
+use rm_referee_protocol::*;
+
+fn close(a: &[(f32, f32)], b: &[(f32, f32)]) -> bool {
+    a.len() == b.len()
+        && a.iter()
+            .zip(b)
+            .all(|(p, q)| (p.0 - q.0).abs() < 0.051 && (p.1 - q.1).abs() < 0.051)
+}
+
+#[test]
+fn test_map_path_short_path_round_trips_through_wire() {
+    let waypoints = [(1.0, 2.0), (3.5, 2.0), (3.5, 6.25), (20.0, 7.0)];
+    let path = MapPath::from_waypoints(&waypoints, PathIntention::Attack).unwrap();
+    assert_eq!(path.start_dm(), (10, 20));
+
+    let cmd = path.to_cmd_data(EndpointId::RedSentry);
+    let bytes = cmd.to_bytes().unwrap();
+    let parsed = RefereeFrameCmdData::try_from(bytes.as_slice()).unwrap();
+    let decoded = MapPath::from_cmd_data(&parsed).unwrap();
+    assert_eq!(decoded, path);
+    assert_eq!(decoded.intention(), PathIntention::Attack);
+    // 16.5m 的线段超过 i8 分米范围，被拆成两段
+    let polyline = decoded.waypoints();
+    assert_eq!(polyline.len(), 5);
+    assert!(close(
+        &[polyline[0], polyline[1], polyline[2]],
+        &waypoints[..3]
+    ));
+    assert!(close(&polyline[4..], &waypoints[3..]));
+}
+
+#[test]
+fn test_map_path_long_path_is_resampled_to_49_deltas() {
+    // 100 个点的之字形，总长远超 49 段
+    let waypoints: Vec<(f32, f32)> = (0..100)
+        .map(|i| (i as f32 * 0.25, if i % 2 == 0 { 1.0 } else { 2.0 }))
+        .collect();
+    let path = MapPath::from_waypoints(&waypoints, PathIntention::Move).unwrap();
+    assert_eq!(path.deltas().count(), MAP_PATH_DELTAS);
+    let polyline = path.waypoints();
+    assert!(polyline.len() <= MAP_PATH_DELTAS + 1);
+    assert_eq!(polyline.first(), Some(&(0.0, 1.0)));
+    let end = *polyline.last().unwrap();
+    assert!((end.0 - 24.75).abs() < 0.051 && (end.1 - 2.0).abs() < 0.051);
+}
+
+#[test]
+fn test_map_path_rejects_bad_input() {
+    assert_eq!(
+        MapPath::from_waypoints(&[], PathIntention::Defend),
+        Err(MapPathError::Empty)
+    );
+    assert_eq!(
+        MapPath::from_waypoints(&[(1.0, 1.0), (f32::NAN, 0.0)], PathIntention::Defend),
+        Err(MapPathError::InvalidWaypoint(1))
+    );
+    assert_eq!(
+        MapPath::from_waypoints(&[(-1.0, 1.0)], PathIntention::Defend),
+        Err(MapPathError::InvalidWaypoint(0))
+    );
+    let short = RefereeFrameCmdData::MapPathData {
+        intention: 1,
+        start_position_x: 0,
+        start_position_y: 0,
+        delta_x: vec![0; 10],
+        delta_y: vec![0; 49],
+        sender_id: EndpointId::RedSentry,
+    };
+    assert_eq!(MapPath::from_cmd_data(&short), Err(MapPathError::Malformed));
+    assert_eq!(
+        PathIntention::try_from(4),
+        Err(MapPathError::UnknownIntention(4))
+    );
+}
@@ -0,0 +1,138 @@
+use deku::DekuContainerWrite;
+use rm_referee_protocol::*;
+
+mod common;
+use common::ms;
+
+fn radar_info(chance: u8, active: bool) -> RefereeFrameCmdData {
+    RefereeFrameCmdData::RadarInfo {
+        double_vulnerability_chance: chance,
+        enemy_in_double_vulnerability: active,
+    }
+}
+
+#[test]
+fn test_radar_trigger_only_when_allowed() {
+    let mut radar = RadarCommander::new(RobotID::BlueRadar);
+    // 尚未收到 0x020E
+    assert!(radar.trigger_frame(0, ms(0)).unwrap().is_none());
+
+    radar.update(&radar_info(0, false), ms(0));
+    assert!(radar.trigger_frame(0, ms(0)).unwrap().is_none());
+
+    radar.update(&radar_info(1, false), ms(1_000));
+    let frame = radar.trigger_frame(9, ms(1_000)).unwrap().unwrap();
+    let bytes = frame.to_bytes().unwrap();
+    let parsed = RefereeFrame::try_from(bytes.as_slice()).unwrap();
+    assert_eq!(
+        parsed.cmd_data.as_radar_cmd(),
+        Some(RadarCmd { radar_cmd: 1 })
+    );
+    let RefereeFrameCmdData::RobotInteractionData {
+        sender_id,
+        receiver_id,
+        ..
+    } = parsed.cmd_data
+    else {
+        unreachable!()
+    };
+    assert_eq!(sender_id, EndpointId::BlueRadar);
+    assert_eq!(receiver_id, EndpointId::Server);
+
+    // 等待确认期间不允许再次触发
+    assert!(radar.is_pending());
+    assert!(radar.trigger_frame(10, ms(1_000)).unwrap().is_none());
+}
+
+#[test]
+fn test_radar_trigger_confirmation() {
+    let mut radar = RadarCommander::resume(RobotID::RedRadar, 3);
+    radar.update(&radar_info(2, false), ms(0));
+    radar.trigger_frame(0, ms(100)).unwrap().unwrap();
+    assert_eq!(radar.last_sent(), 4);
+    // 机会数减少即判定成功，无需等待
+    assert_eq!(
+        radar.update(&radar_info(1, true), ms(200)),
+        Some(RadarTriggerOutcome::Succeeded)
+    );
+    assert!(!radar.can_trigger());
+    assert_eq!(radar.update(&radar_info(1, true), ms(1_200)), None);
+
+    radar.update(&radar_info(1, false), ms(2_200));
+    radar.trigger_frame(1, ms(2_300)).unwrap().unwrap();
+    // 发送后立即到达的 0x020E 可能早于服务器处理，机会数未变时继续等待
+    assert_eq!(radar.update(&radar_info(1, false), ms(2_400)), None);
+    assert!(radar.is_pending());
+    assert!(!radar.can_trigger());
+    assert_eq!(
+        radar.update(&radar_info(1, false), ms(3_400)),
+        Some(RadarTriggerOutcome::Failed)
+    );
+    assert_eq!(radar.last_sent(), 5);
+    assert!(radar.can_trigger());
+}
+
+#[test]
+fn test_radar_trigger_timeout() {
+    let mut radar = RadarCommander::new(RobotID::RedRadar).with_settle_delay(ms(500), ms(2_000));
+    radar.update(&radar_info(1, false), ms(0));
+    radar.trigger_frame(0, ms(0)).unwrap().unwrap();
+    let other = RefereeFrameCmdData::GameStatus {
+        game_type: GameType::RMUC,
+        game_progress: GameProgress::InProgress,
+        stage_remain_time: 100,
+        sync_time_stamp: 0,
+    };
+    // 0x020E 丢失：超时前保持等待，超时后报告超时而非失败
+    assert_eq!(radar.update(&other, ms(1_999)), None);
+    assert_eq!(
+        radar.update(&other, ms(2_000)),
+        Some(RadarTriggerOutcome::TimedOut)
+    );
+    assert!(radar.can_trigger());
+}
+
+#[test]
+fn test_minimap_publisher_slots_and_sentinel() {
+    let mut minimap = MinimapPublisher::new(RobotID::RedRadar);
+    assert!(minimap.track(RobotID::BlueHero, FieldPoint::new(12.345, 6.7)));
+    assert!(minimap.track(RobotID::BlueSentry, FieldPoint::new(30.0, -1.0)));
+    assert!(minimap.track(RobotID::BlueInfantry4, FieldPoint::ORIGIN));
+    // 己方机器人、无对应字段的机器人与非有限坐标不记录
+    assert!(!minimap.track(RobotID::RedHero, FieldPoint::new(1.0, 1.0)));
+    assert!(!minimap.track(RobotID::BlueAerial, FieldPoint::new(1.0, 1.0)));
+    assert!(!minimap.track(RobotID::BlueEngineer, FieldPoint::new(f32::NAN, 1.0)));
+    assert_eq!(minimap.tracked_len(), 3);
+
+    let data = minimap.to_cmd_data();
+    assert_eq!(
+        data,
+        RefereeFrameCmdData::MapRobotData {
+            hero_position_x: 1235,
+            hero_position_y: 670,
+            engineer_position_x: 0,
+            engineer_position_y: 0,
+            infantry_3_position_x: 0,
+            infantry_3_position_y: 0,
+            infantry_4_position_x: 1,
+            infantry_4_position_y: 0,
+            infantry_5_position_x: 0,
+            infantry_5_position_y: 0,
+            sentry_position_x: 3000,
+            sentry_position_y: 0,
+        }
+    );
+    assert_eq!(data.to_bytes().unwrap().len(), 2 + 24);
+
+    assert!(minimap.untrack(RobotID::BlueHero));
+    assert!(!minimap.untrack(RobotID::BlueHero));
+}
+
+#[test]
+fn test_minimap_publisher_rate_limit() {
+    let mut minimap = MinimapPublisher::new(RobotID::BlueRadar).with_rate(50);
+    minimap.track(RobotID::RedEngineer, FieldPoint::new(3.0, 4.0));
+    assert!(minimap.poll(ms(0)).is_some());
+    assert!(minimap.poll(ms(50)).is_none());
+    assert!(minimap.poll(ms(100)).is_some());
+}
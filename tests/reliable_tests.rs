@@ -0,0 +1,181 @@
+use core::time::Duration;
+
+use rm_referee_protocol::*;
+
+mod common;
+use common::ms;
+
+const GOTO_EXCHANGE: u16 = 0x0210;
+
+// 两端以 10ms 步长运行，drop(n) 为 true 时丢弃第 n 帧
+fn simulate(
+    a: &mut ReliableLink,
+    b: &mut ReliableLink,
+    from: u64,
+    until: u64,
+    mut drop: impl FnMut(usize) -> bool,
+) -> Vec<RefereeFrameCmdData> {
+    let mut delivered = Vec::new();
+    let mut n = 0;
+    for t in (from..until).step_by(10) {
+        if let Some(frame) = a.poll(ms(t)) {
+            n += 1;
+            if !drop(n) {
+                delivered.extend(b.receive(&frame));
+            }
+        }
+        if let Some(frame) = b.poll(ms(t)) {
+            n += 1;
+            if !drop(n) {
+                delivered.extend(a.receive(&frame));
+            }
+        }
+    }
+    delivered
+}
+
+#[test]
+fn test_reliable_delivers_exactly_once_over_lossy_link() {
+    let mut hero = ReliableLink::new(EndpointId::RedHero, 0, 30);
+    let mut engineer = ReliableLink::new(EndpointId::RedEngineer, 0, 30);
+    for i in 0..5u8 {
+        hero.send(EndpointId::RedEngineer, GOTO_EXCHANGE, &[i])
+            .unwrap();
+    }
+    // 丢弃每三帧中的一帧，数据与确认都会丢失
+    let delivered = simulate(&mut hero, &mut engineer, 0, 5_000, |n| n % 3 == 0);
+    let payloads: Vec<u8> = delivered
+        .iter()
+        .map(|d| match d {
+            RefereeFrameCmdData::RobotInteractionData {
+                data_cmd_id,
+                sender_id,
+                user_data,
+                ..
+            } => {
+                assert_eq!(*data_cmd_id, GOTO_EXCHANGE);
+                assert_eq!(*sender_id, EndpointId::RedHero);
+                user_data[0]
+            }
+            other => panic!("unexpected {other:?}"),
+        })
+        .collect();
+    let mut sorted = payloads.clone();
+    sorted.sort();
+    assert_eq!(sorted, [0, 1, 2, 3, 4]);
+    assert_eq!(hero.in_flight(), 0);
+    assert!(hero.take_failures().is_empty());
+}
+
+#[test]
+fn test_reliable_respects_rate_limit() {
+    let mut link = ReliableLink::new(EndpointId::RedHero, 0, 100);
+    for _ in 0..3 {
+        link.send(EndpointId::RedSentry, GOTO_EXCHANGE, &[])
+            .unwrap();
+    }
+    assert!(link.poll(ms(0)).is_some());
+    assert!(link.poll(ms(20)).is_none());
+    assert!(link.poll(ms(34)).is_some());
+}
+
+#[test]
+fn test_reliable_gives_up_after_bounded_retries() {
+    let mut link =
+        ReliableLink::new(EndpointId::RedHero, 0, 30).with_retry(Duration::from_millis(100), 3);
+    let seq = link
+        .send(EndpointId::RedEngineer, GOTO_EXCHANGE, &[1])
+        .unwrap();
+    let sent = (0..100).filter_map(|t| link.poll(ms(t * 10))).count();
+    assert_eq!(sent, 3);
+    assert_eq!(link.in_flight(), 0);
+    assert_eq!(
+        link.take_failures(),
+        [DeliveryFailure {
+            receiver_id: EndpointId::RedEngineer,
+            seq,
+            data_cmd_id: GOTO_EXCHANGE,
+        }]
+    );
+}
+
+#[test]
+fn test_reliable_duplicates_are_acked_but_not_redelivered() {
+    let mut hero = ReliableLink::new(EndpointId::RedHero, 0, 30);
+    let mut engineer = ReliableLink::new(EndpointId::RedEngineer, 0, 30);
+    hero.send(EndpointId::RedEngineer, GOTO_EXCHANGE, &[7])
+        .unwrap();
+    let frame = hero.poll(ms(0)).unwrap();
+    assert!(engineer.receive(&frame).is_some());
+    assert!(engineer.receive(&frame).is_none());
+    // 两次接收只需一条确认
+    let ack = engineer.poll(ms(0)).unwrap();
+    assert!(engineer.poll(ms(100)).is_none());
+    assert!(hero.receive(&ack).is_none());
+    assert_eq!(hero.in_flight(), 0);
+
+    assert_eq!(
+        hero.send(EndpointId::RedEngineer, 0x0100, &[]),
+        Err(MessageError::IdOutOfRange(0x0100))
+    );
+    assert_eq!(
+        ReliableLink::new(EndpointId::RedHero, 0, 30).with_data_cmd_id(0x0100),
+        Err(MessageError::IdOutOfRange(0x0100))
+    );
+    assert_eq!(
+        hero.send(EndpointId::RedEngineer, GOTO_EXCHANGE, &[0; 108]),
+        Err(MessageError::TooLong(108))
+    );
+}
+
+#[test]
+fn test_reliable_restarted_sender_is_delivered_with_new_session() {
+    let mut hero = ReliableLink::new(EndpointId::RedHero, 1, 30);
+    let mut engineer = ReliableLink::new(EndpointId::RedEngineer, 0, 30);
+    for i in 0..70u8 {
+        hero.send(EndpointId::RedEngineer, GOTO_EXCHANGE, &[i])
+            .unwrap();
+    }
+    assert_eq!(
+        simulate(&mut hero, &mut engineer, 0, 10_000, |_| false).len(),
+        70
+    );
+
+    // 英雄重启后序号从 0 开始，会话号不同时接收窗口重置
+    let mut hero = ReliableLink::new(EndpointId::RedHero, 2, 30);
+    hero.send(EndpointId::RedEngineer, GOTO_EXCHANGE, &[0xAA])
+        .unwrap();
+    let delivered = simulate(&mut hero, &mut engineer, 10_000, 11_000, |_| false);
+    assert!(matches!(
+        delivered.as_slice(),
+        [RefereeFrameCmdData::RobotInteractionData { user_data, .. }] if user_data == &[0xAA]
+    ));
+    assert_eq!(hero.in_flight(), 0);
+}
+
+#[test]
+fn test_reliable_stale_frames_are_not_acked() {
+    let mut hero = ReliableLink::new(EndpointId::RedHero, 0, 30).with_retry(ms(100), 2);
+    let mut engineer = ReliableLink::new(EndpointId::RedEngineer, 0, 30);
+    for i in 0..70u8 {
+        hero.send(EndpointId::RedEngineer, GOTO_EXCHANGE, &[i])
+            .unwrap();
+    }
+    simulate(&mut hero, &mut engineer, 0, 10_000, |_| false);
+
+    // 同一会话重启：序号 0 落在窗口之外，不确认，发送方重试耗尽后报告失败而非静默丢失
+    let mut hero = ReliableLink::new(EndpointId::RedHero, 0, 30).with_retry(ms(100), 2);
+    let seq = hero
+        .send(EndpointId::RedEngineer, GOTO_EXCHANGE, &[0xAA])
+        .unwrap();
+    assert!(simulate(&mut hero, &mut engineer, 10_000, 11_000, |_| false).is_empty());
+    assert!(engineer.poll(ms(11_000)).is_none());
+    assert_eq!(
+        hero.take_failures(),
+        [DeliveryFailure {
+            receiver_id: EndpointId::RedEngineer,
+            seq,
+            data_cmd_id: GOTO_EXCHANGE,
+        }]
+    );
+}
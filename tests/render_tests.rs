@@ -0,0 +1,143 @@
+use rm_referee_protocol::render::{BLUE_SELF_COLOR, palette};
+use rm_referee_protocol::*;
+
+mod common;
+use common::pt;
+
+fn rgba([r, g, b]: [u8; 3]) -> Option<[u8; 4]> {
+    Some([r, g, b, 0xFF])
+}
+
+#[test]
+fn test_render_line_width_and_palette() {
+    let mut hud = HudRenderer::for_robot(RobotID::BlueHero);
+    hud.add(Figure::line(
+        *b"ln0",
+        0,
+        FigureColor::SelfColor,
+        5,
+        pt(100, 500),
+        pt(300, 500),
+    ));
+    hud.add(Figure::circle(
+        *b"ci0",
+        0,
+        FigureColor::Cyan,
+        1,
+        pt(960, 540),
+        100,
+    ));
+    let canvas = hud.render();
+    assert_eq!((canvas.width(), canvas.height()), (1920, 1080));
+
+    assert_eq!(canvas.pixel(200, 500), rgba(BLUE_SELF_COLOR));
+    assert_eq!(canvas.pixel(200, 502), rgba(BLUE_SELF_COLOR));
+    assert_eq!(canvas.pixel(200, 504), Some([0, 0, 0, 0]));
+    let cyan = rgba(palette(FigureColor::Cyan, BLUE_SELF_COLOR));
+    assert_eq!(canvas.pixel(1060, 540), cyan);
+    assert_eq!(canvas.pixel(960, 640), cyan);
+    assert_eq!(canvas.pixel(960, 540), Some([0, 0, 0, 0]));
+}
+
+#[test]
+fn test_render_layer_order_and_arc() {
+    let mut hud = HudRenderer::new().with_background([0, 0, 0, 0xFF]);
+    // 高图层先加入，仍应绘制在上方
+    hud.add(Figure::line(
+        *b"top",
+        5,
+        FigureColor::Green,
+        9,
+        pt(0, 100),
+        pt(400, 100),
+    ));
+    hud.add(Figure::line(
+        *b"bot",
+        1,
+        FigureColor::Orange,
+        9,
+        pt(200, 0),
+        pt(200, 400),
+    ));
+    // 从正上方顺时针到正右方的四分之一圆弧
+    hud.add(Figure::arc(
+        *b"arc",
+        0,
+        FigureColor::White,
+        3,
+        pt(1000, 500),
+        (0, 90),
+        (50, 50),
+    ));
+    let canvas = hud.render();
+
+    assert_eq!(
+        canvas.pixel(200, 100),
+        rgba(palette(FigureColor::Green, [0; 3]))
+    );
+    assert_eq!(
+        canvas.pixel(200, 300),
+        rgba(palette(FigureColor::Orange, [0; 3]))
+    );
+    let white = Some([0xFF; 4]);
+    assert_eq!(canvas.pixel(1035, 535), white);
+    assert_eq!(canvas.pixel(965, 535), Some([0, 0, 0, 0xFF]));
+    assert_eq!(canvas.pixel(1035, 465), Some([0, 0, 0, 0xFF]));
+}
+
+#[test]
+fn test_render_custom_character_from_user_data() {
+    let text = TextFigure::new(*b"txt", 2, FigureColor::Yellow, 14, 2, pt(50, 1000), "H").unwrap();
+    let mut hud = HudRenderer::new();
+    hud.add_user_data(&text.to_user_data(FigureOperateType::Add));
+    let canvas = hud.render();
+    let yellow = rgba(palette(FigureColor::Yellow, [0; 3]));
+    // 'H' 的左竖笔画位于首列，自起点向下延伸
+    assert_eq!(canvas.pixel(50, 1000), yellow);
+    assert_eq!(canvas.pixel(50, 990), yellow);
+    assert_eq!(canvas.pixel(55, 995), Some([0, 0, 0, 0]));
+    assert!(canvas.pixel(51, 986).is_some_and(|p| p[3] == 0));
+
+    // 删除操作不绘制
+    let mut hud = HudRenderer::new();
+    hud.add_user_data(&text.to_user_data(FigureOperateType::Delete));
+    assert_eq!(hud.render().pixel(50, 1000), Some([0, 0, 0, 0]));
+}
+
+#[test]
+fn test_render_png_and_svg_outputs() {
+    let mut hud = HudRenderer::new();
+    hud.add(Figure::rect(
+        *b"rc0",
+        0,
+        FigureColor::Pink,
+        2,
+        pt(10, 20),
+        pt(110, 70),
+    ));
+    hud.add(
+        TextFigure::new(
+            *b"txt",
+            1,
+            FigureColor::White,
+            20,
+            2,
+            pt(10, 1079),
+            "a<b\nc",
+        )
+        .unwrap(),
+    );
+
+    let png = hud.to_png().unwrap();
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    assert_eq!(&png[16..24], &[0, 0, 0x07, 0x80, 0, 0, 0x04, 0x38]);
+
+    let svg = hud.to_svg();
+    assert!(svg.starts_with("<svg "));
+    // y 轴翻转：左下角 (10, 20) 与 (110, 70) 的矩形在 SVG 中顶部为 1079 - 70
+    assert!(svg.contains("<rect x=\"10\" y=\"1009\" width=\"100\" height=\"50\""));
+    assert!(svg.contains(">a&lt;b</tspan><tspan x=\"10\" dy=\"30\">c</tspan>"));
+    let rect_at = svg.find("<rect").unwrap();
+    let text_at = svg.find("<text").unwrap();
+    assert!(rect_at < text_at);
+}
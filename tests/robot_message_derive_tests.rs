@@ -0,0 +1,88 @@
+use deku::prelude::*;
+use rm_referee_protocol::*;
+
+#[derive(RobotMessage)]
+#[deku_derive(DekuRead, DekuWrite)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[robot_message(id = 0x0203)]
+struct SentryIntent {
+    #[deku(bits = 4)]
+    mode: u8,
+    #[deku(bits = 4)]
+    lane: u8,
+    #[deku(temp, temp_value = "0")]
+    reserved: u8,
+    target: RobotID,
+    #[deku(endian = "little")]
+    position: [f32; 2],
+}
+
+#[derive(Debug, Clone, PartialEq, DekuRead, DekuWrite, RobotMessage)]
+#[robot_message(id = 0x02FF, serde = false)]
+struct FullPayload {
+    #[deku(pad_bytes_before = "2")]
+    data: [u8; 110],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, DekuRead, DekuWrite, RobotMessage)]
+#[robot_message(id = 0x0204)]
+struct Ping(u16, bool);
+
+#[test]
+fn test_derived_sizes_and_ids() {
+    assert_eq!(SentryIntent::DATA_CMD_ID, 0x0203);
+    assert_eq!(<SentryIntent as WireSize>::BITS, 4 + 4 + 8 + 8 + 64);
+    assert_eq!(<FullPayload as WireSize>::BITS, 112 * 8);
+    assert_eq!(<Ping as WireSize>::BITS, 24);
+    assert!(ROBOT_MESSAGE_IDS.contains(&FullPayload::DATA_CMD_ID));
+}
+
+#[test]
+fn test_derived_message_roundtrip_and_dispatch() {
+    let intent = SentryIntent {
+        mode: 2,
+        lane: 5,
+        target: RobotID::BlueHero,
+        position: [3.5, -1.25],
+    };
+    let cmd = intent
+        .to_cmd_data(EndpointId::RedSentry, EndpointId::RedRadar)
+        .unwrap();
+    let RefereeFrameCmdData::RobotInteractionData { user_data, .. } = &cmd else {
+        panic!()
+    };
+    assert_eq!(user_data.len(), <SentryIntent as WireSize>::BITS / 8);
+
+    let mut got = Vec::new();
+    let mut registry = MessageRegistry::new();
+    registry.register(|_, m: SentryIntent| got.push(m)).unwrap();
+    registry.register(|_, _: Ping| {}).unwrap();
+    assert_eq!(registry.dispatch(&cmd), Ok(0x0203));
+    drop(registry);
+    assert_eq!(got, [intent]);
+
+    let full = FullPayload { data: [7; 110] };
+    let cmd = full
+        .to_cmd_data(EndpointId::RedSentry, EndpointId::RedRadar)
+        .unwrap();
+    assert_eq!(FullPayload::from_cmd_data(&cmd), Ok(full));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_derived_message_serde() {
+    let intent = SentryIntent {
+        mode: 1,
+        lane: 0,
+        target: RobotID::RedHero,
+        position: [0.5, 2.0],
+    };
+    let json = serde_json::to_string(&intent).unwrap();
+    assert_eq!(
+        json,
+        r#"{"mode":1,"lane":0,"target":"RedHero","position":[0.5,2.0]}"#
+    );
+    assert_eq!(serde_json::from_str::<SentryIntent>(&json).unwrap(), intent);
+    let ping: Ping = serde_json::from_str("[7,true]").unwrap();
+    assert_eq!(ping, Ping(7, true));
+}
@@ -0,0 +1,11 @@
+// 编译期长度检查：超过 0x0301 负载上限的消息无法编译。
+// .stderr 快照包含 rustc 的诊断措辞，只在录制快照的工具链上比对
+#[rustversion::attr(
+    not(stable(1.95)),
+    ignore = "stderr snapshots are recorded with rustc 1.95"
+)]
+#[test]
+fn test_robot_message_derive_compile_errors() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,177 @@
+use core::cell::RefCell;
+
+use deku::prelude::*;
+use rm_referee_protocol::*;
+
+#[deku_derive(DekuRead, DekuWrite)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[deku(endian = "little")]
+struct TargetPose {
+    x: f32,
+    y: f32,
+    target: u8,
+}
+
+impl RobotMessage for TargetPose {
+    const DATA_CMD_ID: u16 = 0x0201;
+
+    fn encode(&self) -> Result<Vec<u8>, DekuError> {
+        self.to_bytes()
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self, DekuError> {
+        Self::from_bytes((bytes, 0)).map(|(_, v)| v)
+    }
+}
+
+#[deku_derive(DekuRead, DekuWrite)]
+#[derive(Debug, Clone, PartialEq)]
+struct Blob {
+    data: [u8; 113],
+}
+
+impl RobotMessage for Blob {
+    const DATA_CMD_ID: u16 = 0x0202;
+
+    fn encode(&self) -> Result<Vec<u8>, DekuError> {
+        self.to_bytes()
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self, DekuError> {
+        Self::from_bytes((bytes, 0)).map(|(_, v)| v)
+    }
+}
+
+#[deku_derive(DekuRead, DekuWrite)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Misplaced {
+    value: u8,
+}
+
+impl RobotMessage for Misplaced {
+    const DATA_CMD_ID: u16 = 0x0120;
+
+    fn encode(&self) -> Result<Vec<u8>, DekuError> {
+        self.to_bytes()
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self, DekuError> {
+        Self::from_bytes((bytes, 0)).map(|(_, v)| v)
+    }
+}
+
+#[test]
+fn test_robot_message_roundtrip_through_frame() {
+    let pose = TargetPose {
+        x: 1.5,
+        y: -2.0,
+        target: 3,
+    };
+    let cmd = pose
+        .to_cmd_data(EndpointId::RedSentry, EndpointId::RedHero)
+        .unwrap();
+    let frame = RefereeFrame::new(7, cmd).unwrap();
+    let bytes = frame.to_bytes().unwrap();
+    let (_, parsed) = RefereeFrame::from_bytes((&bytes, 0)).unwrap();
+    assert_eq!(
+        parsed.cmd_data.as_robot_comm_raw().map(<[u8]>::len),
+        Some(9)
+    );
+    assert_eq!(TargetPose::from_cmd_data(&parsed.cmd_data), Ok(pose));
+    assert_eq!(
+        Misplaced::from_cmd_data(&parsed.cmd_data),
+        Err(MessageError::UnknownId(0x0201))
+    );
+}
+
+#[test]
+fn test_registry_dispatches_by_id() {
+    let received = RefCell::new(Vec::new());
+    let mut registry = MessageRegistry::new();
+    registry
+        .register(|sender, pose: TargetPose| received.borrow_mut().push((sender, pose)))
+        .unwrap();
+    assert_eq!(
+        registry.register(|_, _: TargetPose| {}),
+        Err(MessageError::DuplicateId(0x0201))
+    );
+
+    let pose = TargetPose {
+        x: 0.25,
+        y: 4.0,
+        target: 1,
+    };
+    let cmd = pose
+        .to_cmd_data(EndpointId::BlueRadar, EndpointId::BlueSentry)
+        .unwrap();
+    assert_eq!(registry.dispatch(&cmd), Ok(0x0201));
+    let unknown = RefereeFrameCmdData::RobotInteractionData {
+        data_cmd_id: 0x02FF,
+        sender_id: EndpointId::BlueRadar,
+        receiver_id: EndpointId::BlueSentry,
+        user_data: vec![1, 2],
+    };
+    assert_eq!(
+        registry.dispatch(&unknown),
+        Err(MessageError::UnknownId(0x02FF))
+    );
+    let truncated = RefereeFrameCmdData::RobotInteractionData {
+        data_cmd_id: 0x0201,
+        sender_id: EndpointId::BlueRadar,
+        receiver_id: EndpointId::BlueSentry,
+        user_data: vec![0; 4],
+    };
+    assert!(matches!(
+        registry.dispatch(&truncated),
+        Err(MessageError::Decode(_))
+    ));
+    drop(registry);
+    assert_eq!(received.into_inner(), [(EndpointId::BlueRadar, pose)]);
+}
+
+#[test]
+fn test_registry_rejects_out_of_range_and_oversized() {
+    let mut registry = MessageRegistry::new();
+    assert_eq!(
+        registry.register(|_, _: Misplaced| {}),
+        Err(MessageError::IdOutOfRange(0x0120))
+    );
+    assert_eq!(
+        Misplaced { value: 1 }.to_cmd_data(EndpointId::RedHero, EndpointId::RedSentry),
+        Err(MessageError::IdOutOfRange(0x0120))
+    );
+    assert_eq!(
+        Blob { data: [0; 113] }.to_cmd_data(EndpointId::RedHero, EndpointId::RedSentry),
+        Err(MessageError::TooLong(113))
+    );
+
+    registry.register(|_, _: Blob| {}).unwrap();
+    let oversized = RefereeFrameCmdData::RobotInteractionData {
+        data_cmd_id: 0x0202,
+        sender_id: EndpointId::RedHero,
+        receiver_id: EndpointId::RedSentry,
+        user_data: vec![0; 113],
+    };
+    assert_eq!(
+        registry.dispatch(&oversized),
+        Err(MessageError::TooLong(113))
+    );
+    let figure = RobotInteractionUserData::LayerDelete(InteractionLayerDelete {
+        delete_type: LayerDeleteType::DeleteAll,
+        layer: 0,
+    })
+    .to_cmd_data(EndpointId::RedHero, EndpointId::RedHeroClient)
+    .unwrap();
+    assert_eq!(
+        registry.dispatch(&figure),
+        Err(MessageError::IdOutOfRange(0x0100))
+    );
+    assert_eq!(
+        registry.dispatch(&RefereeFrameCmdData::RobotPos {
+            x: 0.0,
+            y: 0.0,
+            angle: 0.0
+        }),
+        Err(MessageError::NotInteraction)
+    );
+}
@@ -0,0 +1,177 @@
+use rm_referee_protocol::*;
+
+#[test]
+fn test_screen_point_range_checks() {
+    assert!(ScreenPoint::new(1919, 1079).is_ok());
+    assert_eq!(ScreenPoint::new(1920, 0), Err(ScreenError::OutOfRange));
+    assert_eq!(ScreenPoint::new(0, 1080), Err(ScreenError::OutOfRange));
+    assert_eq!(ScreenPoint::clamped(-5, 5000), ScreenPoint::TOP_LEFT);
+    assert_eq!(
+        ScreenPoint::with_policy(2500, -1, BoundsPolicy::Clamp),
+        Ok(ScreenPoint::BOTTOM_RIGHT)
+    );
+    assert_eq!(
+        ScreenPoint::with_policy(2500, -1, BoundsPolicy::Error),
+        Err(ScreenError::OutOfRange)
+    );
+}
+
+#[test]
+fn test_screen_point_origin_and_offsets() {
+    // 原点在左下角：设计稿中的顶部一行对应 y = 1079
+    let top = ScreenPoint::from_top_left(100, 0).unwrap();
+    assert_eq!((top.x(), top.y()), (100, 1079));
+    assert_eq!(
+        ScreenPoint::from_top_left(0, 1080),
+        Err(ScreenError::OutOfRange)
+    );
+
+    let p = ScreenPoint::CENTER.offset(-60, 40).unwrap();
+    assert_eq!((p.x(), p.y()), (900, 580));
+    assert!(ScreenPoint::TOP_RIGHT.offset(1, 0).is_err());
+    assert_eq!(
+        ScreenPoint::TOP_RIGHT.offset_clamped(10, 10),
+        ScreenPoint::TOP_RIGHT
+    );
+}
+
+#[test]
+fn test_figure_scalars_saturate_at_bit_widths() {
+    let circle = Figure::circle(
+        *b"ci0",
+        1,
+        FigureColor::Green,
+        5000,
+        ScreenPoint::CENTER,
+        4000,
+    )
+    .to_interaction(FigureOperateType::Add);
+    assert_eq!(circle.width, 1023);
+    assert_eq!(circle.details_c, 1023);
+
+    let arc = Figure::arc(
+        *b"ar0",
+        1,
+        FigureColor::Green,
+        2,
+        ScreenPoint::CENTER,
+        (400, 600),
+        (3000, 10),
+    )
+    .to_interaction(FigureOperateType::Add);
+    assert_eq!((arc.details_a, arc.details_b), (400, 511));
+    assert_eq!((arc.details_d, arc.details_e), (2047, 10));
+}
+
+#[test]
+fn test_figure_try_to_interaction_rejects_out_of_range() {
+    let add = FigureOperateType::Add;
+    let circle = |width, radius| {
+        Figure::circle(
+            *b"ci0",
+            1,
+            FigureColor::Green,
+            width,
+            ScreenPoint::CENTER,
+            radius,
+        )
+    };
+    assert_eq!(
+        circle(1023, 1023).try_to_interaction(add),
+        Ok(circle(1023, 1023).to_interaction(add))
+    );
+    assert_eq!(
+        circle(1024, 10).try_to_interaction(add),
+        Err(FigureError::Width)
+    );
+    assert_eq!(
+        circle(2, 1024).try_to_interaction(add),
+        Err(FigureError::Radius)
+    );
+
+    let arc = |angles, radii| {
+        Figure::arc(
+            *b"ar0",
+            1,
+            FigureColor::Green,
+            2,
+            ScreenPoint::CENTER,
+            angles,
+            radii,
+        )
+    };
+    assert!(arc((0, 360), (2047, 2047)).try_to_interaction(add).is_ok());
+    assert_eq!(
+        arc((0, 361), (10, 10)).try_to_interaction(add),
+        Err(FigureError::Angle)
+    );
+    assert_eq!(
+        arc((0, 90), (2048, 10)).try_to_interaction(add),
+        Err(FigureError::Radius)
+    );
+
+    let float = |value| {
+        Figure::float(
+            *b"fl0",
+            1,
+            FigureColor::Green,
+            20,
+            2,
+            ScreenPoint::CENTER,
+            value,
+        )
+    };
+    assert!(float(-2_147_483.5).try_to_interaction(add).is_ok());
+    assert_eq!(
+        float(f32::NAN).try_to_interaction(add),
+        Err(FigureError::Value)
+    );
+    assert_eq!(
+        float(3.0e6).try_to_interaction(add),
+        Err(FigureError::Value)
+    );
+
+    let text = |layer, font_size, length| {
+        Figure::text(
+            *b"tx0",
+            layer,
+            FigureColor::Green,
+            font_size,
+            2,
+            ScreenPoint::CENTER,
+            length,
+        )
+    };
+    assert!(text(15, 511, 30).try_to_interaction(add).is_ok());
+    assert_eq!(
+        text(16, 20, 5).try_to_interaction(add),
+        Err(FigureError::Layer)
+    );
+    assert_eq!(
+        text(1, 512, 5).try_to_interaction(add),
+        Err(FigureError::FontSize)
+    );
+    assert_eq!(
+        text(1, 20, 512).try_to_interaction(add),
+        Err(FigureError::Length)
+    );
+}
+
+#[test]
+fn test_screen_rect_bounds() {
+    // 右、上边界不含在内：全屏区域合法
+    assert!(ScreenRect::new(ScreenPoint::BOTTOM_LEFT, 1920, 1080).is_ok());
+    assert!(ScreenRect::new(ScreenPoint::new(1800, 0).unwrap(), 120, 10).is_ok());
+    assert_eq!(
+        ScreenRect::new(ScreenPoint::new(1800, 0).unwrap(), 121, 10),
+        Err(ScreenError::OutOfRange)
+    );
+    assert_eq!(
+        ScreenRect::new(ScreenPoint::BOTTOM_LEFT, 1920, 1081),
+        Err(ScreenError::OutOfRange)
+    );
+    let rect = ScreenRect::centered(ScreenPoint::CENTER, 200, 100).unwrap();
+    assert_eq!((rect.origin().x(), rect.origin().y()), (860, 490));
+    assert_eq!(rect.center(), ScreenPoint::CENTER);
+    assert_eq!(rect.point(500, 500), rect.point(200, 100));
+}
@@ -0,0 +1,137 @@
+use deku::DekuContainerWrite;
+use rm_referee_protocol::*;
+
+fn sentry_info(status: SentryStatus) -> RefereeFrameCmdData {
+    let info = u32::from(status.exchanged_projectile_allowance)
+        | u32::from(status.remote_projectile_exchanges) << 11
+        | u32::from(status.remote_hp_exchanges) << 15
+        | u32::from(status.can_confirm_free_revive) << 19;
+    RefereeFrameCmdData::SentryInfo {
+        sentry_info: info,
+        sentry_info_2: 0,
+    }
+}
+
+#[test]
+fn test_sentry_frame_roundtrip_with_robot_sender() {
+    let mut commander = SentryCommander::new(RobotID::RedSentry);
+    assert!(!commander.has_pending());
+    assert!(commander.request_projectiles(100));
+    commander.confirm_revive();
+
+    let frame = commander.next_frame(3).unwrap();
+    let bytes = frame.to_bytes().unwrap();
+    let parsed = RefereeFrame::try_from(bytes.as_slice()).unwrap();
+    assert_eq!(frame, parsed);
+    let RefereeFrameCmdData::RobotInteractionData {
+        sender_id,
+        receiver_id,
+        ..
+    } = parsed.cmd_data
+    else {
+        unreachable!()
+    };
+    assert_eq!(sender_id, EndpointId::RedSentry);
+    assert_eq!(sender_id.robot_id(), Some(RobotID::RedSentry));
+    assert_eq!(receiver_id, EndpointId::Server);
+    let cmd = parsed.cmd_data.as_sentry_cmd().unwrap();
+    assert_eq!(cmd.exchange_projectile_allowance, 100);
+    assert!(cmd.confirm_revive);
+    assert!(!commander.has_pending());
+}
+
+#[test]
+fn test_sentry_cmd_wire_bytes_match_spec() {
+    let mut commander = SentryCommander::new(RobotID::RedSentry);
+    commander.confirm_revive();
+    assert!(commander.request_projectiles(100));
+    commander.request_remote_projectiles();
+    let frame = commander.next_frame(0).unwrap();
+    let RefereeFrameCmdData::RobotInteractionData { user_data, .. } = &frame.cmd_data else {
+        unreachable!()
+    };
+    // 小端 u32：bit 0 确认复活，bit 2-12 发弹量 100，bit 13-16 远程兑换次数 1
+    assert_eq!(user_data, &(1u32 | 100 << 2 | 1 << 13).to_le_bytes());
+    assert_eq!(user_data, &[0x91, 0x21, 0x00, 0x00]);
+
+    let cmd = SentryCmd {
+        confirm_revive: false,
+        confirm_immediate_revive: true,
+        exchange_projectile_allowance: 0x7FF,
+        remote_exchange_projectile_count: 0xF,
+        remote_exchange_hp_count: 0xF,
+    };
+    assert_eq!(cmd.to_bytes().unwrap(), (0x1F_FFFEu32).to_le_bytes());
+    assert_eq!(
+        SentryCmd::try_from(&[0x91, 0x21, 0x00, 0x00][..]).unwrap(),
+        SentryCmd {
+            confirm_revive: true,
+            confirm_immediate_revive: false,
+            exchange_projectile_allowance: 100,
+            remote_exchange_projectile_count: 1,
+            remote_exchange_hp_count: 0,
+        }
+    );
+}
+
+#[test]
+fn test_sentry_counters_step_and_wrap() {
+    let mut commander = SentryCommander::new(RobotID::BlueSentry);
+    for _ in 0..17 {
+        commander.request_remote_hp();
+    }
+    commander.request_remote_projectiles();
+    let mut last = commander.last_sent();
+    let mut frames = 0;
+    while commander.has_pending() {
+        commander.next_frame(0).unwrap();
+        let cmd = commander.last_sent();
+        // 每帧计数只 +1（4 bit 回绕）
+        assert_eq!(
+            cmd.remote_exchange_hp_count,
+            (last.remote_exchange_hp_count + 1) & 0x0F
+        );
+        last = cmd;
+        frames += 1;
+    }
+    assert_eq!(frames, 17);
+    assert_eq!(last.remote_exchange_hp_count, 1);
+    assert_eq!(last.remote_exchange_projectile_count, 1);
+
+    assert!(!commander.request_projectiles(2048));
+    assert!(commander.request_projectiles(2047));
+    assert!(!commander.request_projectiles(1));
+}
+
+#[test]
+fn test_sentry_reconciles_with_sentry_info() {
+    let mut commander = SentryCommander::new(RobotID::RedSentry);
+    commander.request_projectiles(50);
+    commander.next_frame(0).unwrap();
+    assert_eq!(commander.unconfirmed_projectiles(), 50);
+
+    // 服务器反馈领先于本地（本地重启后丢失了历史）
+    let status = SentryStatus {
+        exchanged_projectile_allowance: 300,
+        remote_projectile_exchanges: 4,
+        remote_hp_exchanges: 2,
+        can_confirm_free_revive: true,
+        ..Default::default()
+    };
+    assert!(commander.update(&sentry_info(status)));
+    assert_eq!(commander.status(), Some(&status));
+    assert_eq!(commander.unconfirmed_projectiles(), 0);
+
+    commander.request_projectiles(10);
+    commander.request_remote_hp();
+    let cmd = commander.next_cmd();
+    assert_eq!(cmd.exchange_projectile_allowance, 310);
+    assert_eq!(cmd.remote_exchange_hp_count, 3);
+    assert_eq!(cmd.remote_exchange_projectile_count, 4);
+
+    assert!(!commander.update(&RefereeFrameCmdData::RobotPos {
+        x: 0.0,
+        y: 0.0,
+        angle: 0.0
+    }));
+}
@@ -0,0 +1,11 @@
+use deku::prelude::*;
+use rm_referee_protocol::*;
+
+#[derive(Debug, Clone, PartialEq, DekuRead, DekuWrite, RobotMessage)]
+#[robot_message(id = 0x0200, serde = false)]
+struct Oversized {
+    #[deku(pad_bytes_before = "3")]
+    data: [u8; 110],
+}
+
+fn main() {}
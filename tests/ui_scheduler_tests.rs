@@ -0,0 +1,108 @@
+use rm_referee_protocol::*;
+
+mod common;
+use common::{line, ms};
+
+fn names(data: &RobotInteractionUserData) -> Vec<([u8; 3], FigureOperateType)> {
+    let figs: &[InteractionFigure] = match data {
+        RobotInteractionUserData::Figure(f) => core::slice::from_ref(f),
+        RobotInteractionUserData::Figures2(f) => f,
+        RobotInteractionUserData::Figures5(f) => f,
+        RobotInteractionUserData::Figures7(f) => f,
+        RobotInteractionUserData::CustomCharacter { figure, .. } => core::slice::from_ref(figure),
+        _ => &[],
+    };
+    figs.iter()
+        .map(|f| (f.figure_name, f.operate_type))
+        .collect()
+}
+
+#[test]
+fn test_scheduler_rate_limit_and_priority() {
+    // 超过上限的频率按 30Hz 处理
+    let mut sched = UiScheduler::new(1000);
+    let mut scene = HudScene::new();
+    scene.set(line(b"aaa", 10));
+    scene.set(line(b"bbb", 20));
+    scene.set(line(b"ccc", 30));
+    sched.set_priority(*b"ccc", UiPriority::Critical);
+    sched.set_priority(*b"aaa", UiPriority::Low);
+    sched.sync(&mut scene);
+    assert_eq!(sched.pending_len(), 3);
+
+    let first = sched.poll(ms(0)).unwrap();
+    // 3 条可批量操作取单条最大批量 2，关键图形优先
+    assert_eq!(
+        names(&first),
+        [
+            (*b"ccc", FigureOperateType::Add),
+            (*b"bbb", FigureOperateType::Add)
+        ]
+    );
+    assert!(sched.poll(ms(20)).is_none());
+    let second = sched.poll(ms(34)).unwrap();
+    assert_eq!(names(&second), [(*b"aaa", FigureOperateType::Add)]);
+    assert!(sched.poll(ms(100)).is_none());
+}
+
+#[test]
+fn test_scheduler_coalesces_same_name() {
+    let mut sched = UiScheduler::new(10);
+    let mut scene = HudScene::new();
+    scene.set(line(b"aaa", 10));
+    sched.sync(&mut scene);
+    scene.set(line(b"aaa", 50));
+    sched.sync(&mut scene);
+    // 新增 + 修改合并为一次新增，内容为最新值
+    assert_eq!(sched.pending_len(), 1);
+    let RobotInteractionUserData::Figure(fig) = sched.poll(ms(0)).unwrap() else {
+        panic!("expected 0x0101");
+    };
+    assert_eq!(fig.operate_type, FigureOperateType::Add);
+    assert_eq!(fig.start_x, 50);
+
+    // 新增未发出即删除：什么都不发
+    scene.set(line(b"bbb", 10));
+    sched.sync(&mut scene);
+    scene.remove(b"bbb");
+    sched.sync(&mut scene);
+    assert_eq!(sched.pending_len(), 0);
+
+    // 删除未发出又重新出现：改为修改
+    scene.remove(b"aaa");
+    sched.sync(&mut scene);
+    scene.set(line(b"aaa", 70));
+    sched.sync(&mut scene);
+    let data = sched.poll(ms(200)).unwrap();
+    assert_eq!(names(&data), [(*b"aaa", FigureOperateType::Modify)]);
+}
+
+#[test]
+fn test_scheduler_round_robin_resync() {
+    let mut sched = UiScheduler::new(10).with_resync(ms(500));
+    let mut scene = HudScene::new();
+    for i in 0..9u8 {
+        scene.set(line(&[b'f', b'0', b'0' + i], u16::from(i)));
+    }
+    sched.sync(&mut scene);
+    // 首个时隙即为重同步时隙，此时全部图形仍待发送，退回正常队列
+    assert_eq!(names(&sched.poll(ms(0)).unwrap()).len(), 7);
+    assert_eq!(names(&sched.poll(ms(100)).unwrap()).len(), 2);
+    assert!(sched.poll(ms(200)).is_none());
+
+    // 重同步按名称轮询并以新增形式发送
+    let r1 = names(&sched.poll(ms(500)).unwrap());
+    assert_eq!(r1.len(), 7);
+    assert!(r1.iter().all(|(_, op)| *op == FigureOperateType::Add));
+    assert_eq!(r1[0].0, *b"f00");
+    let r2 = names(&sched.poll(ms(1000)).unwrap());
+    assert_eq!(r2[0].0, *b"f07");
+    // 轮询回绕：f07 f08 之后接着 f00 起凑满一条
+    assert_eq!(r2.len(), 7);
+    assert_eq!(r2[2].0, *b"f00");
+    let r3 = names(&sched.poll(ms(1500)).unwrap());
+    assert_eq!(r3[0].0, *b"f05");
+
+    sched.client_reset();
+    assert_eq!(sched.pending_len(), 9);
+}
@@ -0,0 +1,198 @@
+use core::time::Duration;
+
+use rm_referee_protocol::*;
+
+mod common;
+use common::{line, pt};
+
+fn line_on(name: &[u8; 3], layer: u8) -> Figure {
+    Figure {
+        layer,
+        ..line(name, 10)
+    }
+}
+
+fn send(client: &mut VirtualClient, data: &RobotInteractionUserData) -> Vec<ClientViolation> {
+    let cmd = data
+        .to_cmd_data(EndpointId::RedInfantry3, EndpointId::RedInfantry3Client)
+        .unwrap();
+    client.consume(&cmd)
+}
+
+#[test]
+fn test_virtual_client_tracks_hud_scene_end_to_end() {
+    let mut client = VirtualClient::new(EndpointId::RedInfantry3Client);
+    let mut scene = HudScene::new();
+    for i in 0..9u8 {
+        scene.set(line_on(&[b'a', b'0' + i, b'0'], 1));
+    }
+    scene.set(TextFigure::new(*b"txt", 2, FigureColor::White, 20, 2, pt(50, 50), "HP").unwrap());
+    let mut round = |scene: &mut HudScene| {
+        scene
+            .update()
+            .iter()
+            .flat_map(|data| send(&mut client, data))
+            .collect::<Vec<_>>()
+    };
+    assert!(round(&mut scene).is_empty());
+
+    scene.remove(b"a00");
+    scene.set(line_on(b"a10", 3));
+    scene.set(TextFigure::new(*b"txt", 2, FigureColor::White, 20, 2, pt(50, 50), "MP").unwrap());
+    assert!(round(&mut scene).is_empty());
+
+    assert_eq!(client.len(), 9);
+    assert_eq!(client.layer_len(3), 1);
+    let held: Vec<HudElement> = client.figures().copied().collect();
+    let desired: Vec<HudElement> = scene.elements().copied().collect();
+    assert_eq!(held, desired);
+}
+
+#[test]
+fn test_virtual_client_flags_operation_misuse() {
+    let mut client = VirtualClient::new(EndpointId::RedInfantry3Client);
+    let add =
+        |f: Figure| RobotInteractionUserData::Figure(f.to_interaction(FigureOperateType::Add));
+    assert!(send(&mut client, &add(line_on(b"ln0", 1))).is_empty());
+    // 与已有图形相同的新增只记为冗余，内容不同的新增才是错误
+    let redundant = send(&mut client, &add(line_on(b"ln0", 1)));
+    assert_eq!(redundant, [ClientViolation::RedundantAdd(*b"ln0")]);
+    assert!(!redundant[0].is_error());
+    let duplicate = send(&mut client, &add(line_on(b"ln0", 2)));
+    assert_eq!(duplicate, [ClientViolation::DuplicateAdd(*b"ln0")]);
+    assert!(duplicate[0].is_error());
+    assert_eq!(
+        send(&mut client, &add(line_on(b"ln1", 12))),
+        [ClientViolation::InvalidLayer(12)]
+    );
+
+    // 批量中的空操作与对不存在图形的修改 / 删除
+    let batch = RobotInteractionUserData::Figures2([
+        line_on(b"zz0", 1).to_interaction(FigureOperateType::Modify),
+        line_on(b"zz1", 1).to_interaction(FigureOperateType::None),
+    ]);
+    assert_eq!(
+        send(&mut client, &batch),
+        [
+            ClientViolation::ModifyMissing(*b"zz0"),
+            ClientViolation::NoneOperation(*b"zz1")
+        ]
+    );
+    let delete = RobotInteractionUserData::Figure(
+        line_on(b"zz2", 1).to_interaction(FigureOperateType::Delete),
+    );
+    assert_eq!(
+        send(&mut client, &delete),
+        [ClientViolation::DeleteMissing(*b"zz2")]
+    );
+
+    // 字符图形只能通过 0x0110 携带内容
+    let text = Figure::text(*b"tx0", 1, FigureColor::White, 20, 2, pt(0, 0), 4);
+    assert_eq!(
+        send(&mut client, &add(text)),
+        [ClientViolation::TextWithoutContent(*b"tx0")]
+    );
+
+    // 屏幕外的坐标不会被截断，而是记为误用；删除不检查坐标
+    let mut off_screen = line_on(b"ln2", 1).to_interaction(FigureOperateType::Add);
+    off_screen.details_d = 2000;
+    assert_eq!(
+        send(&mut client, &RobotInteractionUserData::Figure(off_screen)),
+        [ClientViolation::InvalidFigure(
+            *b"ln2",
+            FigureError::OffScreen { x: 2000, y: 200 }
+        )]
+    );
+    off_screen.operate_type = FigureOperateType::Delete;
+    assert_eq!(
+        send(&mut client, &RobotInteractionUserData::Figure(off_screen)),
+        [ClientViolation::DeleteMissing(*b"ln2")]
+    );
+    assert_eq!(client.len(), 1);
+}
+
+#[test]
+fn test_virtual_client_layers_limits_and_addressing() {
+    let mut client = VirtualClient::new(EndpointId::RedInfantry3Client).with_figure_limit(2);
+    let adds = RobotInteractionUserData::Figures5([
+        line_on(b"f00", 1).to_interaction(FigureOperateType::Add),
+        line_on(b"f01", 2).to_interaction(FigureOperateType::Add),
+        line_on(b"f02", 2).to_interaction(FigureOperateType::Add),
+        line_on(b"f03", 2).to_interaction(FigureOperateType::None),
+        line_on(b"f04", 2).to_interaction(FigureOperateType::None),
+    ]);
+    let violations = send(&mut client, &adds);
+    assert_eq!(violations[0], ClientViolation::LimitExceeded(*b"f02"));
+    assert_eq!(client.len(), 2);
+
+    let delete_layer = |delete_type, layer| {
+        RobotInteractionUserData::LayerDelete(InteractionLayerDelete { delete_type, layer })
+    };
+    assert_eq!(
+        send(&mut client, &delete_layer(LayerDeleteType::DeleteLayer, 10)),
+        [ClientViolation::InvalidLayer(10)]
+    );
+    assert!(send(&mut client, &delete_layer(LayerDeleteType::DeleteLayer, 2)).is_empty());
+    assert_eq!(client.layer_len(1), 1);
+    assert_eq!(
+        send(&mut client, &delete_layer(LayerDeleteType::None, 0)),
+        [ClientViolation::NoneLayerDelete]
+    );
+    assert!(send(&mut client, &delete_layer(LayerDeleteType::DeleteAll, 0)).is_empty());
+    assert!(client.is_empty());
+
+    // 其他机器人发给本选手端的图形不被接受；发给其他选手端的帧忽略
+    let data =
+        RobotInteractionUserData::Figure(line_on(b"x00", 1).to_interaction(FigureOperateType::Add));
+    let foreign = data
+        .to_cmd_data(EndpointId::RedHero, EndpointId::RedInfantry3Client)
+        .unwrap();
+    assert_eq!(
+        client.consume(&foreign),
+        [ClientViolation::WrongSender(EndpointId::RedHero)]
+    );
+    let elsewhere = data
+        .to_cmd_data(EndpointId::RedHero, EndpointId::RedHeroClient)
+        .unwrap();
+    assert!(client.consume(&elsewhere).is_empty());
+    assert!(client.is_empty());
+}
+
+#[test]
+fn test_virtual_client_accepts_scheduler_resync() {
+    let mut sched = UiScheduler::new(10).with_resync(Duration::from_millis(500));
+    let mut client = VirtualClient::new(EndpointId::RedInfantry3Client);
+    let mut scene = HudScene::new();
+    for i in 0..9u8 {
+        scene.set(line_on(&[b'r', b'0' + i, b'0'], 1));
+    }
+    scene.set(TextFigure::new(*b"txt", 2, FigureColor::White, 20, 2, pt(50, 50), "HP").unwrap());
+    let mut violations = Vec::new();
+    for t in 0..40u64 {
+        if t == 15 {
+            scene.set(line_on(b"r00", 4));
+            scene.remove(b"r10");
+        }
+        sched.sync(&mut scene);
+        if let Some(data) = sched.poll(Duration::from_millis(t * 100)) {
+            violations.extend(send(&mut client, &data));
+        }
+    }
+    // 重同步重发未变化的图形，只产生冗余新增
+    assert!(!violations.is_empty());
+    assert!(violations.iter().all(|v| !v.is_error()));
+    let held: Vec<HudElement> = client.figures().copied().collect();
+    let desired: Vec<HudElement> = scene.elements().copied().collect();
+    assert_eq!(held, desired);
+
+    // 选手端重启后由重同步恢复全部图形
+    client.reset();
+    violations.clear();
+    for t in 40..80u64 {
+        if let Some(data) = sched.poll(Duration::from_millis(t * 100)) {
+            violations.extend(send(&mut client, &data));
+        }
+    }
+    assert!(violations.iter().all(|v| !v.is_error()));
+    assert_eq!(client.len(), desired.len());
+}
@@ -0,0 +1,167 @@
+use rm_referee_protocol::*;
+
+fn area(x: u16, y: u16, width: u16, height: u16) -> ScreenRect {
+    ScreenRect::new(ScreenPoint::new(x, y).unwrap(), width, height).unwrap()
+}
+
+fn power_heat(buffer_energy: u16, heat_17mm_1: u16) -> RefereeFrameCmdData {
+    RefereeFrameCmdData::PowerHeatData {
+        buffer_energy,
+        shooter_17mm_1_barrel_heat: heat_17mm_1,
+        shooter_17mm_2_barrel_heat: 0,
+        shooter_42mm_barrel_heat: 0,
+    }
+}
+
+fn robot_status(heat_limit: u16) -> RefereeFrameCmdData {
+    RefereeFrameCmdData::RobotStatus {
+        robot_id: RobotID::RedInfantry3,
+        robot_level: 1,
+        current_hp: 200,
+        maximum_hp: 200,
+        shooter_barrel_cooling_value: 40,
+        shooter_barrel_heat_limit: heat_limit,
+        chassis_power_limit: 60,
+        power_management_gimbal_output: true,
+        power_management_chassis_output: true,
+        power_management_shooter_output: true,
+    }
+}
+
+#[test]
+fn test_heat_bar_tracks_referee_state() {
+    let mut bar = HeatBar::new(
+        *b"ht",
+        3,
+        area(100, 100, 204, 14),
+        ShooterNumber::Shooter17mm1,
+    );
+    assert!(bar.update(&robot_status(200)));
+    assert!(bar.update(&power_heat(60, 140)));
+    assert!(!bar.update(&RefereeFrameCmdData::RobotPos {
+        x: 0.0,
+        y: 0.0,
+        angle: 0.0
+    }));
+    assert_eq!((bar.heat(), bar.limit()), (140, 200));
+
+    let elements = bar.elements();
+    assert_eq!(elements.len(), 2);
+    assert!(elements.iter().all(|e| e.layer() == 3));
+    let fill = elements[1].figure();
+    assert_eq!(fill.name, *b"ht1");
+    assert_eq!(fill.color, FigureColor::Orange);
+    assert_eq!((fill.start.x(), fill.width), (102, 10));
+    // 内宽 200 像素的 70%
+    assert_eq!(
+        fill.shape,
+        FigureShape::Line {
+            end: ScreenPoint::new(242, 107).unwrap()
+        }
+    );
+
+    let mut energy = BufferEnergyBar::new(*b"be", 3, area(100, 80, 204, 14));
+    energy.update(&power_heat(0, 0));
+    assert_eq!(energy.elements()[1].figure().width, 0);
+}
+
+#[test]
+fn test_crosshair_drop_marks() {
+    let cross = Crosshair::new(
+        *b"cx",
+        1,
+        ScreenRect::centered(ScreenPoint::CENTER, 160, 200).unwrap(),
+    )
+    .with_drop_marks(&[10, 30, 60, 1000, 1, 2, 3, 4, 5, 6]);
+    assert_eq!(cross.drop_marks().len(), Crosshair::MAX_DROP_MARKS);
+    let elements = cross.elements();
+    assert_eq!(elements.len(), 10);
+    assert_eq!(elements[9].name(), *b"cx9");
+
+    let mark = |i: usize| {
+        let fig = elements[i].figure();
+        let FigureShape::Line { end } = fig.shape else {
+            panic!()
+        };
+        (fig.start, end)
+    };
+    let (a, b) = mark(2);
+    assert_eq!((a.y(), b.y()), (530, 530));
+    assert_eq!((a.x(), b.x()), (920, 1000));
+    let (c, d) = mark(3);
+    assert!(d.x() - c.x() < b.x() - a.x());
+    // 超出区域的下坠截断到区域底边
+    assert_eq!(mark(5).0.y(), 440);
+}
+
+#[test]
+fn test_text_widgets_and_scene_updates() {
+    let mut ammo = AmmoCounter::new(*b"am", 2, area(1700, 100, 100, 30), BulletType::Bullet17mm)
+        .with_low_threshold(20);
+    let mut timer = StageTimer::new(*b"tm", 2, area(900, 1000, 120, 30));
+    let mut buff = BuffIndicator::new(*b"bf", 2, area(100, 1000, 600, 20));
+    ammo.update(&RefereeFrameCmdData::ProjectileAllowance {
+        projectile_allowance_17mm: 150,
+        projectile_allowance_42mm: 0,
+        remaining_gold_coin: 0,
+        projectile_allowance_fortress: 0,
+    });
+    timer.update(&RefereeFrameCmdData::GameStatus {
+        game_type: GameType::RMUC,
+        game_progress: GameProgress::InProgress,
+        stage_remain_time: 185,
+        sync_time_stamp: 0,
+    });
+    buff.update(&RefereeFrameCmdData::Buff {
+        recovery_buff: 10,
+        cooling_buff: 5,
+        defense_buff: 50,
+        vulnerability_buff: 0,
+        attack_buff: 100,
+        energy_ge_50: true,
+        energy_ge_30: true,
+        energy_ge_15: true,
+        energy_ge_5: true,
+        energy_ge_1: true,
+    });
+    let count = *ammo.elements()[0].figure();
+    assert!(matches!(count.shape, FigureShape::Int { value: 150, .. }));
+    assert_eq!(count.color, FigureColor::White);
+    assert_eq!(timer.text(), "3:05");
+    assert_eq!(buff.text(), "ATK+100% DEF+50% CD+5 HP+10%");
+
+    let mut scene = HudScene::new();
+    let mut client = VirtualClient::new(EndpointId::RedInfantry3Client);
+    let mut flush = |scene: &mut HudScene| -> usize {
+        let data = scene.update();
+        for d in &data {
+            let cmd = d
+                .to_cmd_data(EndpointId::RedInfantry3, EndpointId::RedInfantry3Client)
+                .unwrap();
+            assert!(client.consume(&cmd).is_empty());
+        }
+        data.len()
+    };
+    ammo.place(&mut scene);
+    timer.place(&mut scene);
+    buff.place(&mut scene);
+    assert_eq!(flush(&mut scene), 3);
+
+    // 仅计时变化：只发送一次字符图形修改
+    timer.update(&RefereeFrameCmdData::GameStatus {
+        game_type: GameType::RMUC,
+        game_progress: GameProgress::InProgress,
+        stage_remain_time: 29,
+        sync_time_stamp: 0,
+    });
+    timer.place(&mut scene);
+    ammo.place(&mut scene);
+    assert_eq!(flush(&mut scene), 1);
+    let HudElement::Text(text) = scene.get(b"tm0").unwrap() else {
+        panic!()
+    };
+    assert_eq!(
+        (text.text(), text.figure().color),
+        ("0:29", FigureColor::Orange)
+    );
+}